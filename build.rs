@@ -0,0 +1,46 @@
+//! Embed the input files, plain or encrypted, into the binary when the `embed-inputs` feature is enabled
+
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=inputs");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let inputs_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("inputs");
+    // One folder of inputs per year, e.g. `inputs/2022/input-01.txt`
+    let mut files = fs::read_dir(&inputs_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .flat_map(|year_dir| fs::read_dir(year_dir).unwrap())
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "txt" || extension == "enc")
+        })
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+
+    let entries = files
+        .iter()
+        .map(|path| {
+            format!(
+                "    ({:?}, include_str!({:?})),\n",
+                path.strip_prefix(&inputs_dir).unwrap().to_str().unwrap(),
+                path.display().to_string()
+            )
+        })
+        .collect::<String>();
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+    fs::write(
+        out_path,
+        format!(
+            "pub static EMBEDDED_INPUTS: &[(&str, &str)] = &[\n{}];\n",
+            entries
+        ),
+    )
+    .unwrap();
+}
@@ -0,0 +1,280 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    error::parse_json, runner::format_duration, Error, Input, InputSet, Params, Part, Solver,
+};
+
+/// Folder holding the baselines, one file per exercise, part, input and parameters, in the target folder of the crate
+pub const BASELINE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/bench");
+
+/// Summary of the timings of repeated runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Compute the statistics of a non-empty list of timings
+    pub fn from_timings(timings: &[Duration]) -> Self {
+        let mut sorted = timings.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().map(|t| t.as_secs_f64()).sum::<f64>() / count;
+        let variance = sorted
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            max: sorted[sorted.len() - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "min_ms": self.min.as_secs_f64() * 1_000.0,
+            "median_ms": self.median.as_secs_f64() * 1_000.0,
+            "mean_ms": self.mean.as_secs_f64() * 1_000.0,
+            "max_ms": self.max.as_secs_f64() * 1_000.0,
+            "stddev_ms": self.stddev.as_secs_f64() * 1_000.0,
+        })
+    }
+}
+
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub part: Part,
+    pub input_set: InputSet,
+    pub params: Params,
+    pub runs: usize,
+    /// Time spent loading the input
+    pub input: Stats,
+    /// Time spent parsing the input into the puzzle
+    pub parse: Stats,
+    /// Time spent solving the part of the parsed puzzle
+    pub solve: Stats,
+}
+
+impl BenchReport {
+    pub fn baseline_path(&self) -> String {
+        let params = if self.params.is_empty() {
+            String::new()
+        } else {
+            format!("-{}", self.params)
+        };
+        format!(
+            "{}/{}/{:02}-{}-{}{}.json",
+            BASELINE_DIR, self.year, self.day, self.part, self.input_set, params
+        )
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "name": self.name,
+            "part": self.part.to_string(),
+            "params": self.params.to_string(),
+            "runs": self.runs,
+            "input": self.input.to_json(),
+            "parse": self.parse.to_json(),
+            "solve": self.solve.to_json(),
+        })
+    }
+
+    pub fn save_baseline(&self) -> Result<(), Error> {
+        let path = self.baseline_path();
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(BASELINE_DIR, e))?;
+        }
+        fs::write(&path, self.to_json().to_string()).map_err(|e| Error::io(&path, e))?;
+        Ok(())
+    }
+
+    /// Load the saved baseline of the benchmark, if any
+    pub fn load_baseline(&self) -> Result<Option<Value>, Error> {
+        let path = self.baseline_path();
+        if !Path::new(&path).exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        let baseline = parse_json(&content, "baseline").map_err(|e| e.in_file(&path))?;
+        Ok(Some(baseline))
+    }
+}
+
+/// Run a part `warmup` times without measuring it, then `runs` times measuring the input loading, the parsing and the
+/// solving separately
+pub fn bench(
+    solver: &dyn Solver,
+    part: Part,
+    input_set: InputSet,
+    params: &Params,
+    runs: usize,
+    warmup: usize,
+) -> Result<BenchReport, Error> {
+    if runs == 0 {
+        return Err(Error::usage("The number of runs must be at least 1"));
+    }
+    let filename = input_set.path(solver.year(), solver.day());
+
+    let mut input_timings = vec![];
+    let mut parse_timings = vec![];
+    let mut solve_timings = vec![];
+    for iteration in 0..warmup + runs {
+        let start = Instant::now();
+        let input = Input::read(&filename, input_set)?.with_params(params.clone());
+        let loaded = Instant::now();
+        let puzzle = solver.parse(&input).map_err(|e| solver.locate(e, &input))?;
+        let parsed = Instant::now();
+        puzzle
+            .solve(&part, &input)
+            .map_err(|e| solver.locate(e, &input))?;
+        let solved = Instant::now();
+
+        if iteration >= warmup {
+            input_timings.push(loaded - start);
+            parse_timings.push(parsed - loaded);
+            solve_timings.push(solved - parsed);
+        }
+    }
+
+    Ok(BenchReport {
+        year: solver.year(),
+        day: solver.day(),
+        name: solver.name(),
+        part,
+        input_set,
+        params: params.clone(),
+        runs,
+        input: Stats::from_timings(&input_timings),
+        parse: Stats::from_timings(&parse_timings),
+        solve: Stats::from_timings(&solve_timings),
+    })
+}
+
+/// Relative change of the median compared to the baseline, positive for a regression
+fn median_change(stats: &Stats, baseline: &Value) -> Option<f64> {
+    let baseline_median = baseline["median_ms"].as_f64()?;
+    if baseline_median == 0.0 {
+        return None;
+    }
+    let median = stats.median.as_secs_f64() * 1_000.0;
+    Some((median - baseline_median) / baseline_median * 100.0)
+}
+
+fn format_change(change: f64) -> String {
+    if change > 0.0 {
+        format!("+{:.1}% (regression)", change)
+    } else {
+        format!("{:.1}% (improvement)", change)
+    }
+}
+
+pub fn format_report(report: &BenchReport, baseline: Option<&Value>) -> String {
+    let mut lines = vec![format!(
+        "day {} {} {}, {} runs",
+        report.day, report.name, report.part, report.runs
+    )];
+    for (phase, stats) in [
+        ("input", &report.input),
+        ("parse", &report.parse),
+        ("solve", &report.solve),
+    ] {
+        let mut line = format!(
+            "{:<5}  min {:>10}  median {:>10}  mean {:>10}  max {:>10}  stddev {:>10}",
+            phase,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.max),
+            format_duration(stats.stddev),
+        );
+        if let Some(change) = baseline.and_then(|b| median_change(stats, &b[phase])) {
+            line.push_str(&format!("  {} vs baseline", format_change(change)));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Year;
+
+    #[test]
+    fn stats_are_computed_from_the_timings() {
+        let timings = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_timings(&timings);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2_500));
+        assert_eq!(stats.mean, Duration::from_micros(2_500));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert_eq!(stats.stddev.as_micros(), 1_118);
+    }
+
+    #[test]
+    fn bench_measures_every_run_after_the_warmup() {
+        let report = bench(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            Part::Part1,
+            InputSet::EXAMPLE,
+            &Params::default(),
+            3,
+            2,
+        )
+        .unwrap();
+        assert_eq!(report.runs, 3);
+        assert!(report.solve.min <= report.solve.max);
+        assert!(report.parse.min <= report.parse.max);
+        assert_eq!(
+            report.baseline_path(),
+            format!("{}/2022/01-part_1-example.json", BASELINE_DIR)
+        );
+        assert!(Path::new(&report.baseline_path()).is_absolute());
+    }
+
+    #[test]
+    fn baselines_are_kept_apart_per_params() {
+        let solver = Year::get(2022).unwrap().find_solver("rope-bridge").unwrap();
+        let params = Params::parse(solver, &[Part::Part1], &["knots=3".to_string()]).unwrap();
+        let report = bench(solver, Part::Part1, InputSet::EXAMPLE, &params, 1, 0).unwrap();
+        assert_eq!(
+            report.baseline_path(),
+            format!("{}/2022/09-part_1-example-knots=3.json", BASELINE_DIR)
+        );
+    }
+
+    #[test]
+    fn changes_are_relative_to_the_baseline_median() {
+        let stats = Stats::from_timings(&[Duration::from_millis(12)]);
+        let change = median_change(&stats, &json!({ "median_ms": 10.0 })).unwrap();
+        assert_eq!(format_change(change), "+20.0% (regression)");
+        let change = median_change(&stats, &json!({ "median_ms": 16.0 })).unwrap();
+        assert_eq!(format_change(change), "-25.0% (improvement)");
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{warn, Answer, Error, Input, Part, Solver};
+
+/// Directory of the cached answers, one folder per year, in the crate whatever the current directory
+pub const CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/cache");
+
+/// Everything an answer depends on: the part, the input set, the parameters, the input bytes and the version of the
+/// solver.
+///
+/// The input set is part of the key as some solvers take defaults depending on it, e.g. the row of the beacons.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    year: u16,
+    day: u8,
+    part: Part,
+    input_set: &'static str,
+    params: String,
+    /// SHA-256 of the input, as hexadecimal digits
+    input_hash: String,
+    version: &'static str,
+}
+
+impl CacheKey {
+    pub fn new(solver: &dyn Solver, part: Part, input: &Input) -> Self {
+        CacheKey {
+            year: solver.year(),
+            day: solver.day(),
+            part,
+            input_set: input.input_set.name,
+            params: input.params.to_string(),
+            input_hash: to_hex(&Sha256::digest(input.contents.as_bytes())),
+            version: solver.version(),
+        }
+    }
+
+    /// File of the entry, named after the day, the part and a digest of the whole key
+    fn path(&self, dir: &Path) -> PathBuf {
+        let digest = Sha256::digest(
+            format!(
+                "{}|{}|{}|{}|{}",
+                self.version, self.input_set, self.params, self.input_hash, self.part
+            )
+            .as_bytes(),
+        );
+        dir.join(self.year.to_string()).join(format!(
+            "{:02}-{}-{}.json",
+            self.day,
+            self.part,
+            &to_hex(&digest)[..16]
+        ))
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part.to_string(),
+            "input_set": self.input_set,
+            "params": self.params,
+            "input_sha256": self.input_hash,
+            "version": self.version,
+        })
+    }
+}
+
+/// Answers already found, stored as one JSON file per key so that `all` does not solve again what has not changed.
+///
+/// Only answers are cached, a failed run is always run again.
+pub struct ResultCache {
+    dir: PathBuf,
+}
+
+impl ResultCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        ResultCache { dir: dir.into() }
+    }
+
+    pub fn current() -> Self {
+        ResultCache::new(CACHE_DIR)
+    }
+
+    /// Cached answer of the key, an unreadable entry being ignored
+    pub fn get(&self, key: &CacheKey) -> Option<Answer> {
+        let path = key.path(&self.dir);
+        let content = fs::read_to_string(&path).ok()?;
+        let entry = match serde_json::from_str::<Value>(&content) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!("Ignoring the cached answer {}, {}", path.display(), e);
+                return None;
+            }
+        };
+        // The digest of the file name is shortened, the key stored in the entry tells it apart from another key
+        if entry["key"] != key.to_json() {
+            return None;
+        }
+        match &entry["answer"] {
+            Value::Number(n) => n.as_i64().map(Answer::Number),
+            Value::String(s) => Some(Answer::Text(s.to_string())),
+            _ => None,
+        }
+    }
+
+    pub fn put(&self, key: &CacheKey, answer: &Answer) -> Result<(), Error> {
+        let path = key.path(&self.dir);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(&parent.display().to_string(), e))?;
+        }
+        let answer = match answer {
+            Answer::Number(n) => json!(n),
+            Answer::Text(s) => json!(s),
+        };
+        let entry = json!({ "key": key.to_json(), "answer": answer });
+        fs::write(&path, entry.to_string()).map_err(|e| Error::io(&path.display().to_string(), e))
+    }
+
+    /// Remove every cached answer, returning the number of answers removed
+    pub fn clear(&self) -> Result<usize, Error> {
+        if !self.dir.exists() {
+            return Ok(0);
+        }
+        let dir = self.dir.display().to_string();
+        let mut removed = 0;
+        for year in fs::read_dir(&self.dir).map_err(|e| Error::io(&dir, e))? {
+            let year = year.map_err(|e| Error::io(&dir, e))?.path();
+            if year.is_dir() {
+                removed += fs::read_dir(&year)
+                    .map_err(|e| Error::io(&year.display().to_string(), e))?
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+                    .count();
+            }
+        }
+        fs::remove_dir_all(&self.dir).map_err(|e| Error::io(&dir, e))?;
+        Ok(removed)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InputSet, Params, Year};
+
+    #[test]
+    fn answers_are_cached_by_part_params_and_input() {
+        let dir = std::env::temp_dir().join(format!("advent-of-code-cache-{}", std::process::id()));
+        let cache = ResultCache::new(&dir);
+        let solver = Year::get(2022).unwrap().find_solver("rope-bridge").unwrap();
+        let input = Input::new("input.txt", "R 4\nU 4\n", InputSet::EXAMPLE);
+        let key = CacheKey::new(solver, Part::Part2, &input);
+        assert_eq!(cache.get(&key), None);
+
+        cache.put(&key, &Answer::Number(1)).unwrap();
+        assert_eq!(cache.get(&key), Some(Answer::Number(1)));
+        assert_eq!(cache.get(&CacheKey::new(solver, Part::Part1, &input)), None);
+        let other_input = Input::new("input.txt", "R 5\nU 4\n", InputSet::EXAMPLE);
+        assert_eq!(
+            cache.get(&CacheKey::new(solver, Part::Part2, &other_input)),
+            None
+        );
+        let other_set = Input::new("input.txt", "R 4\nU 4\n", InputSet::REAL);
+        assert_eq!(
+            cache.get(&CacheKey::new(solver, Part::Part2, &other_set)),
+            None
+        );
+        let params = Params::parse(solver, &[Part::Part2], &["knots=3".to_string()]).unwrap();
+        let input = input.with_params(params);
+        assert_eq!(cache.get(&CacheKey::new(solver, Part::Part2, &input)), None);
+
+        cache
+            .put(
+                &CacheKey::new(solver, Part::Part2, &input),
+                &Answer::Text("#.\n.#\n".to_string()),
+            )
+            .unwrap();
+        assert_eq!(
+            cache.get(&CacheKey::new(solver, Part::Part2, &input)),
+            Some(Answer::Text("#.\n.#\n".to_string()))
+        );
+        assert_eq!(cache.clear().unwrap(), 2);
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.clear().unwrap(), 0);
+    }
+
+    #[test]
+    fn current_cache_is_in_the_crate() {
+        assert!(Path::new(CACHE_DIR).is_absolute());
+        assert!(Path::new(CACHE_DIR).starts_with(env!("CARGO_MANIFEST_DIR")));
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::Error;
+
+/// Cooperative cancellation of a run, checked by the solvers in their main loops.
+///
+/// A run is stopped when its token is cancelled, e.g. by the cancel key of the dashboard, or once its timeout is
+/// elapsed. The clones of a token share its cancellation.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    /// Time limit of the run, with the timeout it was computed from
+    deadline: Option<(Instant, Duration)>,
+}
+
+impl CancelToken {
+    /// Token of a run starting now, which times out after the given duration if any
+    pub fn new(timeout: Option<Duration>) -> Self {
+        CancelToken {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            deadline: timeout.map(|timeout| (Instant::now() + timeout, timeout)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.is_timed_out()
+    }
+
+    fn is_timed_out(&self) -> bool {
+        self.deadline
+            .is_some_and(|(deadline, _)| Instant::now() >= deadline)
+    }
+
+    /// Fail when the run has been cancelled or has timed out, with the progress made so far.
+    ///
+    /// `progress` describes what has been done, e.g. `1234 rocks dropped`, it is only called when the run is stopped.
+    pub fn check(&self, progress: impl FnOnce() -> String) -> Result<(), Error> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(Error::Cancelled {
+                progress: progress(),
+            });
+        }
+        match self.deadline {
+            Some((deadline, timeout)) if Instant::now() >= deadline => Err(Error::TimedOut {
+                timeout,
+                progress: progress(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Parse a duration given on the command line, a number followed by `ms`, `s`, `m` or `h`, seconds by default
+pub fn parse_duration(text: &str) -> Result<Duration, Error> {
+    let invalid = || {
+        Error::usage(format!(
+            "Invalid duration, expected a number followed by `ms`, `s`, `m` or `h`, e.g. `30s`, got {}",
+            text
+        ))
+    };
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (value, unit) = text.split_at(split);
+    let value = value.parse::<f64>().map_err(|_| invalid())?;
+    let seconds = match unit {
+        "ms" => value / 1_000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        "h" => value * 3_600.0,
+        _ => return Err(invalid()),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stopped_runs_fail_with_their_progress() {
+        let token = CancelToken::default();
+        assert!(token.check(|| unreachable!()).is_ok());
+        token.clone().cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(
+            token
+                .check(|| "12 rocks dropped".to_string())
+                .unwrap_err()
+                .to_string(),
+            "Cancelled after 12 rocks dropped"
+        );
+
+        let token = CancelToken::new(Some(Duration::from_millis(1)));
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(
+            token
+                .check(|| "3 minutes simulated".to_string())
+                .unwrap_err()
+                .to_string(),
+            "Timed out after 1.000ms, 3 minutes simulated"
+        );
+        assert!(CancelToken::new(Some(Duration::from_secs(60)))
+            .check(|| unreachable!())
+            .is_ok());
+    }
+
+    #[test]
+    fn durations_have_a_unit() {
+        assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_duration("2").unwrap(), Duration::from_secs(2));
+        assert_eq!(
+            parse_duration("1.5s").unwrap(),
+            Duration::from_millis(1_500)
+        );
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3_600));
+        assert!(parse_duration("0s").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("3 days").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{error::parse_json, Error};
+
+/// Environment variable giving the path of the configuration file
+pub const CONFIG_ENV_VAR: &str = "AOC_CONFIG";
+
+/// Settings of the user, read from a JSON object of settings, e.g. `{ "input_dir": "<path>", "session": "<token>" }`.
+///
+/// The file is given by `AOC_CONFIG`, or else is `advent-of-code/config.json` in the user configuration directory.
+/// Every setting is optional, and relative paths are relative to the directory of the file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Directory of the input files
+    pub input_dir: Option<PathBuf>,
+    /// Session token of the Advent of code website, the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    /// Address of the Advent of code website, e.g. a local server in the tests
+    pub base_url: Option<String>,
+    /// Contact of the user, an email or a repository, sent in the user agent of the requests to the website
+    pub contact: Option<String>,
+    /// File of the answers submitted to the website
+    pub history_file: Option<PathBuf>,
+    /// File of the key of the encrypted inputs
+    pub key_file: Option<PathBuf>,
+}
+
+impl Config {
+    /// Path of the configuration file, `None` if no configuration directory is known
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_ENV_VAR) {
+            return Some(PathBuf::from(path));
+        }
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("advent-of-code").join("config.json"))
+    }
+
+    /// Load the configuration file, a missing file at the default location being an empty configuration
+    pub fn load() -> Result<Self, Error> {
+        let Some(path) = Config::path() else {
+            return Ok(Config::default());
+        };
+        if !path.exists() && env::var_os(CONFIG_ENV_VAR).is_none() {
+            return Ok(Config::default());
+        }
+        let file = path.display().to_string();
+        let content = fs::read_to_string(&path).map_err(|e| Error::io(&file, e))?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        Config::parse(&content, base_dir).map_err(|e| e.in_file(&file))
+    }
+
+    fn parse(content: &str, base_dir: &Path) -> Result<Self, Error> {
+        let root = parse_json(content, "configuration")?;
+        let settings = root.as_object().ok_or(Error::parse(
+            "",
+            "Invalid configuration, expected an object of settings",
+        ))?;
+
+        let mut config = Config::default();
+        for (key, value) in settings {
+            let text = || {
+                value.as_str().map(String::from).ok_or(Error::parse(
+                    &value.to_string(),
+                    format!("Invalid `{}` setting, expected a string", key),
+                ))
+            };
+            match key.as_str() {
+                "input_dir" => config.input_dir = Some(base_dir.join(text()?)),
+                "session" => config.session = Some(text()?),
+                "base_url" => config.base_url = Some(text()?),
+                "contact" => config.contact = Some(text()?),
+                "history_file" => config.history_file = Some(base_dir.join(text()?)),
+                "key_file" => config.key_file = Some(base_dir.join(text()?)),
+                other => {
+                    return Err(Error::parse(
+                        other,
+                        "Unknown setting, expected `input_dir`, `session`, `base_url`, `contact`, `history_file` or `key_file`",
+                    ))
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_are_relative_to_the_configuration_file() {
+        let config = Config::parse(
+            r#"{ "input_dir": "puzzles/inputs" }"#,
+            Path::new("/home/elf/.config/advent-of-code"),
+        )
+        .unwrap();
+        assert_eq!(
+            config.input_dir,
+            Some(PathBuf::from(
+                "/home/elf/.config/advent-of-code/puzzles/inputs"
+            ))
+        );
+        let config =
+            Config::parse(r#"{ "input_dir": "/srv/aoc" }"#, Path::new("/home/elf")).unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("/srv/aoc")));
+    }
+
+    #[test]
+    fn unknown_or_invalid_settings_are_rejected() {
+        assert_eq!(
+            Config::parse("{}", Path::new(".")).unwrap(),
+            Config::default()
+        );
+        assert!(Config::parse(r#"{ "input_directory": "." }"#, Path::new(".")).is_err());
+        assert!(Config::parse(r#"{ "input_dir": 1 }"#, Path::new(".")).is_err());
+        assert!(Config::parse(r#"{ "session": null }"#, Path::new(".")).is_err());
+        assert!(Config::parse(r#"[]"#, Path::new(".")).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Error, ParseError};
+
+/// Render an error for the terminal, parse errors being shown like the diagnostics of rustc:
+///
+/// ```text
+/// error: Expected a number, got `x1`
+///  --> inputs/2022/input-10.txt:3:6
+///   |
+/// 3 | addx x1
+///   |      ^^
+///   |
+///   = hint: expected one instruction per line, `noop` or `addx <number>`
+/// ```
+///
+/// Only the first parse error is rendered unless `all_errors` is set.
+pub fn render(error: &Error, all_errors: bool) -> String {
+    match error {
+        Error::Parse(error) => render_parse_error(error),
+        Error::ParseErrors(errors) if all_errors => {
+            let mut rendered = errors
+                .iter()
+                .map(render_parse_error)
+                .collect::<Vec<String>>();
+            rendered.push(format!(
+                "error: aborting due to {} invalid parts of the input",
+                errors.len()
+            ));
+            rendered.join("\n\n")
+        }
+        Error::ParseErrors(errors) => format!(
+            "{}\n\nnote: {} other invalid parts of the input were found, use `--all-errors` to report them",
+            render_parse_error(&errors[0]),
+            errors.len() - 1
+        ),
+        other => format!("error: {}", other),
+    }
+}
+
+fn render_parse_error(error: &ParseError) -> String {
+    let mut message = error.message.clone();
+    if !error.text.is_empty() {
+        message.push_str(&format!(", got `{}`", error.text));
+    }
+    let mut lines = vec![format!("error: {}", message)];
+
+    // As wide as the line number, and as wide as a single digit without line, so that the `|` columns line up
+    let gutter = " ".repeat(error.line.map_or(1, |l| l.to_string().len()));
+
+    let file = match error.file.as_deref() {
+        Some("-") => Some("<stdin>"),
+        other => other,
+    };
+    let location = [
+        file.map(String::from),
+        error.line.map(|l| l.to_string()),
+        error.column.map(|c| c.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>();
+    if !location.is_empty() {
+        lines.push(format!("{}--> {}", gutter, location.join(":")));
+    }
+
+    if let (Some(line), Some(source_line)) = (error.line, &error.source_line) {
+        lines.push(format!("{} |", gutter));
+        lines.push(format!("{} | {}", line, source_line));
+        if let Some(column) = error.column {
+            let remaining = source_line.chars().count().saturating_sub(column - 1);
+            let width = error.text.chars().count().min(remaining).max(1);
+            lines.push(format!(
+                "{} | {}{}",
+                gutter,
+                " ".repeat(column - 1),
+                "^".repeat(width)
+            ));
+        }
+    }
+
+    if let Some(hint) = &error.hint {
+        lines.push(format!("{} |", gutter));
+        lines.push(format!("{} = hint: expected {}", gutter, hint));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn malformed_instruction(index: usize, line: &str, text: &str) -> Error {
+        Error::parse(text, "Expected a number")
+            .at_line(index, line)
+            .in_file("inputs/2022/input-10.txt")
+            .with_hint("one instruction per line, `noop` or `addx <number>`")
+    }
+
+    #[test]
+    fn parse_error_is_rendered_with_a_caret_under_the_text() {
+        let error = malformed_instruction(2, "addx x1", "x1");
+        assert_eq!(
+            render(&error, false),
+            "error: Expected a number, got `x1`
+ --> inputs/2022/input-10.txt:3:6
+  |
+3 | addx x1
+  |      ^^
+  |
+  = hint: expected one instruction per line, `noop` or `addx <number>`"
+        );
+    }
+
+    #[test]
+    fn only_first_parse_error_is_rendered_by_default() {
+        let error = Error::ParseErrors(vec![
+            match malformed_instruction(2, "addx x1", "x1") {
+                Error::Parse(e) => *e,
+                _ => unreachable!(),
+            },
+            match malformed_instruction(11, "addx y", "y") {
+                Error::Parse(e) => *e,
+                _ => unreachable!(),
+            },
+        ]);
+
+        let first_only = render(&error, false);
+        assert!(first_only.contains("3 | addx x1"));
+        assert!(!first_only.contains("12 | addx y"));
+        assert!(first_only.ends_with(
+            "note: 1 other invalid parts of the input were found, use `--all-errors` to report them"
+        ));
+
+        let all = render(&error, true);
+        assert!(all.contains("3 | addx x1"));
+        assert!(all.contains("12 | addx y\n   |      ^"));
+        assert!(all.ends_with("error: aborting due to 2 invalid parts of the input"));
+    }
+
+    #[test]
+    fn parse_error_without_line_is_rendered_with_the_same_gutter() {
+        let error = Error::parse("", "Empty grid")
+            .in_file("inputs/2022/input-08.txt")
+            .with_hint("one row of tree heights per line");
+        assert_eq!(
+            render(&error, false),
+            "error: Empty grid
+ --> inputs/2022/input-08.txt
+  |
+  = hint: expected one row of tree heights per line"
+        );
+    }
+
+    #[test]
+    fn other_errors_are_rendered_on_one_line() {
+        assert_eq!(
+            render(&Error::no_solution("No path to the top"), true),
+            "error: No solution found: No path to the top"
+        );
+    }
+}
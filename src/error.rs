@@ -0,0 +1,327 @@
+use std::{fmt, io, str::FromStr, time::Duration};
+
+use crate::runner;
+
+/// Errors of the crate, from reading the input to solving the puzzle
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written
+    Io { path: String, source: io::Error },
+    /// The input does not have the expected format
+    Parse(Box<ParseError>),
+    /// Several parts of the input do not have the expected format, in the order of the input
+    ParseErrors(Vec<ParseError>),
+    /// The puzzle reached a state which should not happen with a valid input
+    InvalidState(String),
+    /// The puzzle has been explored without finding a solution
+    NoSolution(String),
+    /// The command line is invalid
+    Usage(String),
+    /// A request to the Advent of code website failed
+    Http { url: String, message: String },
+    /// Some of the runs of a command failed, their errors have already been reported
+    RunsFailed { failed: usize, total: usize },
+    /// The run has been stopped by its timeout, with the progress made so far
+    TimedOut { timeout: Duration, progress: String },
+    /// The run has been cancelled, with the progress made so far
+    Cancelled { progress: String },
+}
+
+/// Location and content of a malformed part of the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// Line number, starting at 1
+    pub line: Option<usize>,
+    /// Column of the offending text in the line, starting at 1
+    pub column: Option<usize>,
+    /// Offending text
+    pub text: String,
+    pub message: String,
+    /// Content of the line holding the offending text
+    pub source_line: Option<String>,
+    /// Description of the expected format
+    pub hint: Option<String>,
+}
+
+impl Error {
+    pub fn io(path: &str, source: io::Error) -> Self {
+        Error::Io {
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn parse(text: &str, message: impl Into<String>) -> Self {
+        Error::Parse(Box::new(ParseError {
+            file: None,
+            line: None,
+            column: None,
+            text: text.to_string(),
+            message: message.into(),
+            source_line: None,
+            hint: None,
+        }))
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        Error::InvalidState(message.into())
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Error::NoSolution(message.into())
+    }
+
+    pub fn usage(message: impl Into<String>) -> Self {
+        Error::Usage(message.into())
+    }
+
+    pub fn http(url: &str, message: impl Into<String>) -> Self {
+        Error::Http {
+            url: url.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Apply a change to every parse error
+    fn map_parse_errors(self, f: impl Fn(&mut ParseError)) -> Self {
+        match self {
+            Error::Parse(mut error) => {
+                f(&mut error);
+                Error::Parse(error)
+            }
+            Error::ParseErrors(mut errors) => {
+                errors.iter_mut().for_each(f);
+                Error::ParseErrors(errors)
+            }
+            other => other,
+        }
+    }
+
+    /// Locate a parse error at the given line of the input, `index` starting at 0.
+    ///
+    /// The column is found by looking for the offending text in the line. An error already located is left unchanged.
+    pub fn at_line(self, index: usize, line: &str) -> Self {
+        self.map_parse_errors(|error| {
+            if error.line.is_some() {
+                return;
+            }
+            error.line = Some(index + 1);
+            error.source_line = Some(line.to_string());
+            if error.column.is_none() && !error.text.is_empty() {
+                error.column = line
+                    .find(error.text.as_str())
+                    .map(|byte| line[..byte].chars().count() + 1);
+            }
+        })
+    }
+
+    /// Locate a parse error at the given column of its line, `index` starting at 0
+    pub fn at_column(self, index: usize) -> Self {
+        self.map_parse_errors(|error| {
+            if error.column.is_none() {
+                error.column = Some(index + 1);
+            }
+        })
+    }
+
+    /// Move a located parse error by `offset` lines, for the errors of a block of lines parsed on its own
+    pub fn shift_lines(self, offset: usize) -> Self {
+        self.map_parse_errors(|error| error.line = error.line.map(|l| l + offset))
+    }
+
+    /// Attach the input file to a parse error
+    pub fn in_file(self, path: &str) -> Self {
+        self.map_parse_errors(|error| {
+            if error.file.is_none() {
+                error.file = Some(path.to_string());
+            }
+        })
+    }
+
+    /// Attach a description of the expected format to a parse error
+    pub fn with_hint(self, hint: &str) -> Self {
+        self.map_parse_errors(|error| {
+            if error.hint.is_none() {
+                error.hint = Some(hint.to_string());
+            }
+        })
+    }
+}
+
+/// Collect the parse errors of an input instead of stopping at the first one
+#[derive(Default)]
+pub struct ErrorCollector {
+    errors: Vec<ParseError>,
+}
+
+impl ErrorCollector {
+    pub fn new() -> Self {
+        ErrorCollector::default()
+    }
+
+    /// Give back the parsed value, or record the parse error and give nothing. Other errors are returned.
+    pub fn check<T>(&mut self, result: Result<T, Error>) -> Result<Option<T>, Error> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(Error::Parse(error)) => {
+                self.errors.push(*error);
+                Ok(None)
+            }
+            Err(Error::ParseErrors(errors)) => {
+                self.errors.extend(errors);
+                Ok(None)
+            }
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Record a parse error found without a parsed value. Other errors are returned.
+    pub fn report(&mut self, error: Error) -> Result<(), Error> {
+        self.check::<()>(Err(error)).map(|_| ())
+    }
+
+    /// Fail with the recorded parse errors, if any
+    pub fn finish(mut self) -> Result<(), Error> {
+        match self.errors.len() {
+            0 => Ok(()),
+            1 => Err(Error::Parse(Box::new(self.errors.remove(0)))),
+            _ => Err(Error::ParseErrors(self.errors)),
+        }
+    }
+}
+
+/// Parse a value, the whole text being reported if it is invalid
+pub fn parse_value<T: FromStr>(text: &str, expected: &str) -> Result<T, Error> {
+    text.parse::<T>()
+        .map_err(|_| Error::parse(text, format!("Expected {}", expected)))
+}
+
+/// Parse a JSON document, a syntax error being located at its line and column
+pub fn parse_json(content: &str, document: &str) -> Result<serde_json::Value, Error> {
+    serde_json::from_str(content).map_err(|e| {
+        Error::Parse(Box::new(ParseError {
+            file: None,
+            line: Some(e.line()),
+            column: Some(e.column()),
+            text: "".to_string(),
+            message: match e.classify() {
+                serde_json::error::Category::Eof => format!("Unexpected end of the {}", document),
+                _ => format!("Invalid JSON in {}", document),
+            },
+            source_line: content
+                .lines()
+                .nth(e.line().saturating_sub(1))
+                .map(String::from),
+            hint: None,
+        }))
+    })
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Unable to access {}: {}", path, source),
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::ParseErrors(errors) => write!(
+                f,
+                "{} (and {} other invalid parts of the input)",
+                errors[0],
+                errors.len() - 1
+            ),
+            Error::InvalidState(message) => write!(f, "Invalid puzzle state: {}", message),
+            Error::NoSolution(message) => write!(f, "No solution found: {}", message),
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Http { url, message } => write!(f, "Request to {} failed: {}", url, message),
+            Error::RunsFailed { failed, total } => write!(f, "{} of {} runs failed", failed, total),
+            Error::TimedOut { timeout, progress } => write!(
+                f,
+                "Timed out after {}, {}",
+                runner::format_duration(*timeout),
+                progress
+            ),
+            Error::Cancelled { progress } => write!(f, "Cancelled after {}", progress),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.file.clone(),
+            self.line.map(|l| l.to_string()),
+            self.column.map(|c| c.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ", got `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors_are_located_in_the_line() {
+        let error = parse_value::<i32>("1O", "a number")
+            .unwrap_err()
+            .at_line(2, "addx 1O")
+            .in_file("inputs/2022/input-10.txt");
+        match &error {
+            Error::Parse(e) => {
+                assert_eq!(e.line, Some(3));
+                assert_eq!(e.column, Some(6));
+            }
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+        assert_eq!(
+            error.to_string(),
+            "inputs/2022/input-10.txt:3:6: Expected a number, got `1O`"
+        );
+    }
+
+    #[test]
+    fn collector_keeps_every_parse_error() {
+        let mut errors = ErrorCollector::new();
+        for (index, line) in ["1", "x", "3", "y"].iter().enumerate() {
+            let value = errors
+                .check(parse_value::<i32>(line, "a number").map_err(|e| e.at_line(index, line)))
+                .unwrap();
+            assert_eq!(value.is_some(), index % 2 == 0);
+        }
+        match errors.finish() {
+            Err(Error::ParseErrors(errors)) => {
+                let lines = errors.iter().map(|e| e.line).collect::<Vec<_>>();
+                assert_eq!(lines, vec![Some(2), Some(4)]);
+            }
+            other => panic!("Expected parse errors, got {:?}", other),
+        }
+        assert!(ErrorCollector::new().finish().is_ok());
+    }
+
+    #[test]
+    fn located_errors_keep_their_first_location() {
+        let error = Error::parse("x", "Unexpected")
+            .at_line(0, "a x")
+            .at_line(5, "x");
+        assert_eq!(error.to_string(), "1:3: Unexpected, got `x`");
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{
+    config::Config,
+    input_key::{self, InputKey},
+    warn, Error, InputSet, YEARS,
+};
+
+/// Environment variable giving the directory of the input files
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
+
+/// Environment variable making the tests on the encrypted inputs fail rather than skip when no key is set
+#[cfg(test)]
+const REQUIRE_INPUT_KEY_ENV_VAR: &str = "AOC_REQUIRE_INPUT_KEY";
+
+/// Prefix of the path of an input embedded in the binary, e.g. `embedded:2022/input-01.txt`
+pub const EMBEDDED_PREFIX: &str = "embedded:";
+
+// Defines `EMBEDDED_INPUTS`, the file name and the contents of every input file, generated by the build script
+#[cfg(feature = "embed-inputs")]
+include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+/// Where the input files are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputDir {
+    Directory(PathBuf),
+    /// The input files embedded in the binary by the `embed-inputs` feature
+    Embedded,
+}
+
+impl InputDir {
+    /// Resolve the input directory from `AOC_INPUT_DIR`, or else from the configuration file, or else use the
+    /// embedded inputs if the binary has them, or else the `inputs` directory of the crate
+    pub fn resolve(env_dir: Option<PathBuf>, config: &Config) -> Self {
+        match env_dir.or(config.input_dir.clone()) {
+            Some(dir) => InputDir::Directory(dir),
+            None if cfg!(feature = "embed-inputs") => InputDir::Embedded,
+            None => InputDir::Directory(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")),
+        }
+    }
+
+    /// Input directory of the process, resolved on first use.
+    ///
+    /// An invalid configuration file is reported and ignored, so that the inputs are still found.
+    pub fn current() -> &'static Self {
+        static CURRENT: OnceLock<InputDir> = OnceLock::new();
+        CURRENT.get_or_init(|| {
+            let config = Config::load().unwrap_or_else(|e| {
+                warn!("Ignoring the configuration file, {}", e);
+                Config::default()
+            });
+            InputDir::resolve(env::var_os(INPUT_DIR_ENV_VAR).map(PathBuf::from), &config)
+        })
+    }
+
+    /// Path of an input file, to be read with `read`
+    pub fn path(&self, file_name: &str) -> String {
+        match self {
+            InputDir::Directory(dir) => dir.join(file_name).display().to_string(),
+            InputDir::Embedded => format!("{}{}", EMBEDDED_PREFIX, file_name),
+        }
+    }
+}
+
+/// Read an input file, or an input embedded in the binary if the path has the embedded prefix.
+///
+/// An input found only encrypted, in the file of the path with the `.enc` suffix, is decrypted with the input key.
+pub fn read(path: &str) -> Result<String, Error> {
+    if is_encrypted(path) {
+        let encrypted_path = input_key::encrypted_path(path);
+        let key = InputKey::current().ok_or_else(|| input_key::missing_key(path))?;
+        return key.decrypt(&read_file(&encrypted_path)?, &encrypted_path);
+    }
+    read_file(path)
+}
+
+/// Whether the input file, or the embedded input, exists, encrypted or not
+pub fn exists(path: &str) -> bool {
+    file_exists(path) || file_exists(&input_key::encrypted_path(path))
+}
+
+/// Whether the input is only found encrypted
+pub fn is_encrypted(path: &str) -> bool {
+    !file_exists(path) && file_exists(&input_key::encrypted_path(path))
+}
+
+/// Skip a test on an encrypted input when no key is set to decrypt it, e.g. in a clone without the key.
+///
+/// The test fails instead when `AOC_REQUIRE_INPUT_KEY` is set, so that a run meant to check the encrypted inputs does
+/// not pass without checking them.
+#[cfg(test)]
+pub fn skip_without_key(path: &str) -> bool {
+    use std::io::Write;
+
+    let skip = is_encrypted(path) && InputKey::current().is_none();
+    if skip {
+        assert!(
+            env::var_os(REQUIRE_INPUT_KEY_ENV_VAR).is_none(),
+            "{} is encrypted and no input key is set, the key is required by `{}`",
+            path,
+            REQUIRE_INPUT_KEY_ENV_VAR
+        );
+        // Written to stderr directly, as the output of a passing test is captured by the test harness
+        let _ = writeln!(
+            io::stderr(),
+            "Skipped, {} is encrypted and no input key is set",
+            path
+        );
+    }
+    skip
+}
+
+/// Encrypt the real input of every day of every year in the directory, the plain files being removed.
+///
+/// Gives the paths of the encrypted files, the inputs already encrypted being left unchanged.
+pub fn encrypt_inputs(input_dir: &InputDir, key: &InputKey) -> Result<Vec<String>, Error> {
+    if *input_dir == InputDir::Embedded {
+        return Err(Error::usage(format!(
+            "The inputs are embedded in the binary, set `{}` to the directory of the inputs to encrypt",
+            INPUT_DIR_ENV_VAR
+        )));
+    }
+    let mut encrypted = Vec::new();
+    for solver in YEARS.iter().flat_map(|year| year.solvers) {
+        let path = input_dir.path(&InputSet::REAL.relative_path(solver.year(), solver.day()));
+        if !Path::new(&path).exists() {
+            continue;
+        }
+        let encrypted_path = input_key::encrypted_path(&path);
+        let content = key.encrypt(&read_file(&path)?);
+        fs::write(&encrypted_path, content).map_err(|e| Error::io(&encrypted_path, e))?;
+        fs::remove_file(&path).map_err(|e| Error::io(&path, e))?;
+        encrypted.push(encrypted_path);
+    }
+    Ok(encrypted)
+}
+
+fn read_file(path: &str) -> Result<String, Error> {
+    match path.strip_prefix(EMBEDDED_PREFIX) {
+        Some(file_name) => embedded(file_name).map(String::from).ok_or_else(|| {
+            Error::io(
+                path,
+                io::Error::new(io::ErrorKind::NotFound, "not embedded in the binary"),
+            )
+        }),
+        None => fs::read_to_string(path).map_err(|e| Error::io(path, e)),
+    }
+}
+
+fn file_exists(path: &str) -> bool {
+    match path.strip_prefix(EMBEDDED_PREFIX) {
+        Some(file_name) => embedded(file_name).is_some(),
+        None => Path::new(path).exists(),
+    }
+}
+
+#[cfg(feature = "embed-inputs")]
+fn embedded(file_name: &str) -> Option<&'static str> {
+    EMBEDDED_INPUTS
+        .iter()
+        .find(|(name, _)| *name == file_name)
+        .map(|(_, contents)| *contents)
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded(_file_name: &str) -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_variable_comes_before_the_configuration() {
+        let config = Config {
+            input_dir: Some(PathBuf::from("/from/config")),
+            ..Config::default()
+        };
+        assert_eq!(
+            InputDir::resolve(Some(PathBuf::from("/from/env")), &config),
+            InputDir::Directory(PathBuf::from("/from/env"))
+        );
+        assert_eq!(
+            InputDir::resolve(None, &config),
+            InputDir::Directory(PathBuf::from("/from/config"))
+        );
+    }
+
+    #[test]
+    fn crate_inputs_are_used_by_default() {
+        let input_dir = InputDir::resolve(None, &Config::default());
+        if cfg!(feature = "embed-inputs") {
+            assert_eq!(input_dir, InputDir::Embedded);
+        } else {
+            assert_eq!(
+                input_dir,
+                InputDir::Directory(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+            );
+        }
+        assert!(exists(&input_dir.path("2022/input-01.txt")));
+        assert!(exists(&input_dir.path("2022/input-01-example.txt")));
+        assert!(read(&input_dir.path("2022/input-01-example.txt")).is_ok());
+        assert!(!exists(&input_dir.path("2022/input-26.txt")));
+        if !skip_without_key(&input_dir.path("2022/input-01.txt")) {
+            assert!(read(&input_dir.path("2022/input-01.txt")).is_ok());
+        }
+    }
+
+    #[test]
+    fn real_inputs_are_replaced_by_their_encrypted_file() {
+        let dir = env::temp_dir().join(format!("advent-of-code-encrypt-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        fs::write(dir.join("2022/input-01.txt"), "1000\n2000\n").unwrap();
+        fs::write(dir.join("2022/input-01-example.txt"), "4000\n").unwrap();
+        let input_dir = InputDir::Directory(dir.clone());
+        let key = InputKey::generate();
+
+        let encrypted = encrypt_inputs(&input_dir, &key).unwrap();
+        assert_eq!(encrypted, vec![input_dir.path("2022/input-01.txt.enc")]);
+        assert!(is_encrypted(&input_dir.path("2022/input-01.txt")));
+        assert!(!is_encrypted(&input_dir.path("2022/input-01-example.txt")));
+        assert_eq!(
+            key.decrypt(&read_file(&encrypted[0]).unwrap(), &encrypted[0])
+                .unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(
+            encrypt_inputs(&input_dir, &key).unwrap(),
+            Vec::<String>::new()
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::{config::Config, warn, Error};
+
+/// Environment variable giving the key of the encrypted inputs, as 64 hexadecimal digits
+pub const KEY_ENV_VAR: &str = "AOC_INPUT_KEY";
+
+/// Suffix of the name of an encrypted input file, e.g. `input-01.txt.enc`
+pub const ENCRYPTED_SUFFIX: &str = ".enc";
+
+/// First line of an encrypted input file, followed by the nonce and the encrypted input in base64
+const HEADER: &str = "advent-of-code encrypted input v1";
+
+const NONCE_LENGTH: usize = 12;
+
+/// Width of the base64 lines of an encrypted input file
+const LINE_WIDTH: usize = 76;
+
+/// Symmetric key of the real inputs, which are committed encrypted with ChaCha20-Poly1305
+#[derive(Clone, PartialEq, Eq)]
+pub struct InputKey([u8; 32]);
+
+impl InputKey {
+    /// New random key
+    pub fn generate() -> Self {
+        InputKey(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Read a key written as 64 hexadecimal digits, the invalid key is not shown in the error, only where it comes
+    /// from, e.g. the environment variable or the key file
+    pub fn from_hex(text: &str, source: &str) -> Result<Self, Error> {
+        let text = text.trim();
+        let invalid = || {
+            Error::usage(format!(
+                "Invalid input key in {}, expected 64 hexadecimal digits",
+                source
+            ))
+        };
+        if text.len() != 64 || !text.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&text[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(InputKey(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Path of the key file, the `key_file` setting or else `input.key` next to the configuration file
+    pub fn default_path(config: &Config) -> Option<PathBuf> {
+        config.key_file.clone().or_else(|| {
+            Config::path().and_then(|path| path.parent().map(|dir| dir.join("input.key")))
+        })
+    }
+
+    /// Load the key from `AOC_INPUT_KEY`, or else from the key file, `None` if neither is set
+    pub fn load(env_key: Option<String>, key_file: Option<&Path>) -> Result<Option<Self>, Error> {
+        if let Some(key) = env_key {
+            return InputKey::from_hex(&key, &format!("`{}`", KEY_ENV_VAR)).map(Some);
+        }
+        let Some(path) = key_file.filter(|path| path.exists()) else {
+            return Ok(None);
+        };
+        let file = path.display().to_string();
+        let content = fs::read_to_string(path).map_err(|e| Error::io(&file, e))?;
+        InputKey::from_hex(&content, &file).map(Some)
+    }
+
+    /// Key of the process, loaded on first use.
+    ///
+    /// An invalid key or configuration file is reported and ignored, the encrypted inputs being then unreadable.
+    pub fn current() -> Option<&'static Self> {
+        static CURRENT: OnceLock<Option<InputKey>> = OnceLock::new();
+        CURRENT
+            .get_or_init(|| {
+                let config = Config::load().unwrap_or_default();
+                InputKey::load(
+                    env::var(KEY_ENV_VAR).ok(),
+                    InputKey::default_path(&config).as_deref(),
+                )
+                .unwrap_or_else(|e| {
+                    warn!("Ignoring the input key, {}", e);
+                    None
+                })
+            })
+            .as_ref()
+    }
+
+    /// Save the key to the key file, only readable by the user
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let file = path.display().to_string();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| Error::io(&dir.display().to_string(), e))?;
+        }
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut key_file = options.open(path).map_err(|e| Error::io(&file, e))?;
+        io::Write::write_all(&mut key_file, format!("{}\n", self.to_hex()).as_bytes())
+            .map_err(|e| Error::io(&file, e))
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(Key::from_slice(&self.0))
+    }
+
+    /// Encrypt an input, giving the content of its encrypted file
+    pub fn encrypt(&self, input: &str) -> String {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let encrypted = self
+            .cipher()
+            .encrypt(&nonce, input.as_bytes())
+            .expect("Encryption of an input in memory does not fail");
+        let data = STANDARD.encode([nonce.as_slice(), &encrypted].concat());
+        let lines = data
+            .as_bytes()
+            .chunks(LINE_WIDTH)
+            .map(|line| String::from_utf8_lossy(line) + "\n")
+            .collect::<String>();
+        format!("{}\n{}", HEADER, lines)
+    }
+
+    /// Decrypt the content of the encrypted file `path`
+    pub fn decrypt(&self, content: &str, path: &str) -> Result<String, Error> {
+        let invalid =
+            |message: &str| Error::io(path, io::Error::new(io::ErrorKind::InvalidData, message));
+        let mut lines = content.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(invalid("not an encrypted input"));
+        }
+        let data = STANDARD
+            .decode(lines.collect::<String>())
+            .map_err(|_| invalid("damaged encrypted input, invalid base64"))?;
+        if data.len() < NONCE_LENGTH {
+            return Err(invalid("damaged encrypted input, too short"));
+        }
+        let (nonce, encrypted) = data.split_at(NONCE_LENGTH);
+        let input = self
+            .cipher()
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|_| {
+                invalid("unable to decrypt, the input key is not the one it was encrypted with")
+            })?;
+        String::from_utf8(input).map_err(|_| invalid("the decrypted input is not UTF-8 text"))
+    }
+}
+
+impl fmt::Debug for InputKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InputKey(..)")
+    }
+}
+
+/// Path of the encrypted file of an input
+pub fn encrypted_path(path: &str) -> String {
+    format!("{}{}", path, ENCRYPTED_SUFFIX)
+}
+
+/// Error of an encrypted input read without a key
+pub fn missing_key(path: &str) -> Error {
+    let key_file = InputKey::default_path(&Config::load().unwrap_or_default())
+        .map(|path| format!(" or write it to {}", path.display()))
+        .unwrap_or_default();
+    Error::io(
+        path,
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "the input is encrypted and no key is set, set `{}`{}",
+                KEY_ENV_VAR, key_file
+            ),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inputs_are_decrypted_with_the_key_they_were_encrypted_with() {
+        let key = InputKey::generate();
+        let input = "1000\n2000\n\n4000\n";
+        let encrypted = key.encrypt(input);
+        assert!(encrypted.starts_with(HEADER));
+        assert!(!encrypted.contains("1000"));
+        assert_eq!(key.decrypt(&encrypted, "input-01.txt.enc").unwrap(), input);
+
+        let other_key = InputKey::generate();
+        assert!(other_key.decrypt(&encrypted, "input-01.txt.enc").is_err());
+        let mut damaged = encrypted.into_bytes();
+        let index = HEADER.len() + 20;
+        damaged[index] = if damaged[index] == b'A' { b'B' } else { b'A' };
+        let damaged = String::from_utf8(damaged).unwrap();
+        assert!(key.decrypt(&damaged, "input-01.txt.enc").is_err());
+    }
+
+    #[test]
+    fn keys_are_written_in_hexadecimal() {
+        let key = InputKey::generate();
+        assert_eq!(InputKey::from_hex(&key.to_hex(), "test").unwrap(), key);
+        assert_eq!(
+            InputKey::from_hex(&format!("{}\n", key.to_hex()), "test").unwrap(),
+            key
+        );
+        assert!(InputKey::from_hex("00ff", "test").is_err());
+        assert!(InputKey::from_hex(&"zz".repeat(32), "test").is_err());
+        let error = InputKey::load(Some("secret".to_string()), None).unwrap_err();
+        assert!(matches!(error, Error::Usage(_)));
+        assert_eq!(
+            error.to_string(),
+            "Invalid input key in `AOC_INPUT_KEY`, expected 64 hexadecimal digits"
+        );
+        assert!(!error.to_string().contains("secret"));
+
+        let path = env::temp_dir().join(format!("advent-of-code-key-{}", std::process::id()));
+        fs::write(&path, "secret\n").unwrap();
+        let error = InputKey::load(None, Some(&path)).unwrap_err();
+        assert!(matches!(error, Error::Usage(_)));
+        assert!(error.to_string().contains(&path.display().to_string()));
+        fs::remove_file(path).unwrap();
+        assert_eq!(InputKey::load(None, None).unwrap(), None);
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::{
+    input_dir, input_dir::InputDir, input_key, manifest::Manifest, Answer, Error, Part, Solver,
+};
+
+/// A named input of a day, e.g. the real puzzle input or the example of the puzzle statement.
+///
+/// The input of a set is read from `input-<day>-<name>.txt` in the input directory, except for the real input which is
+/// read from `input-<day>.txt`. The expected answers of a set are given by the answer manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputSet {
+    /// Name used to select the input with `--input-set`, lowercase words separated by `-`
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+impl InputSet {
+    pub const REAL: InputSet = InputSet {
+        name: "real",
+        description: "puzzle input",
+    };
+
+    pub const EXAMPLE: InputSet = InputSet {
+        name: "example",
+        description: "example of the puzzle statement",
+    };
+
+    /// Name of the input file of the set for a day
+    pub fn file_name(&self, day: u8) -> String {
+        if *self == InputSet::REAL {
+            format!("input-{:02}.txt", day)
+        } else {
+            format!("input-{:02}-{}.txt", day, self.name)
+        }
+    }
+
+    /// Path of the input file of the set for a day relative to the input directory, in the folder of the year
+    pub fn relative_path(&self, year: u16, day: u8) -> String {
+        format!("{}/{}", year, self.file_name(day))
+    }
+
+    /// Path of the input file of the set for a day, in the input directory
+    pub fn path(&self, year: u16, day: u8) -> String {
+        InputDir::current().path(&self.relative_path(year, day))
+    }
+
+    /// Find an input set declared by the solver
+    pub fn find(solver: &dyn Solver, name: &str) -> Result<InputSet, Error> {
+        solver
+            .input_sets()
+            .iter()
+            .find(|set| set.name == name)
+            .copied()
+            .ok_or_else(|| {
+                Error::usage(format!(
+                    "Unknown input set `{}` for exercise {}, expected one of {}",
+                    name,
+                    solver.name(),
+                    solver
+                        .input_sets()
+                        .iter()
+                        .map(|set| format!("`{}`", set.name))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ))
+            })
+    }
+}
+
+/// Check that at least one of the solvers declares the input set, e.g. for a run of every day of a year
+pub fn check_declared(solvers: &[&dyn Solver], name: &str) -> Result<(), Error> {
+    let mut names = solvers
+        .iter()
+        .flat_map(|solver| solver.input_sets())
+        .map(|set| set.name)
+        .collect::<Vec<&str>>();
+    if names.contains(&name) {
+        return Ok(());
+    }
+    names.sort_unstable();
+    names.dedup();
+    Err(Error::usage(format!(
+        "Unknown input set `{}`, expected one of {}",
+        name,
+        names
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )))
+}
+
+/// Describe the input sets of a solver, with their file and their expected answers
+pub fn format_input_sets(solver: &dyn Solver, manifest: &Manifest) -> String {
+    let mut lines = vec![format!(
+        "Input sets of {} (day {} of {}):",
+        solver.name(),
+        solver.day(),
+        solver.year()
+    )];
+    for input_set in solver.input_sets() {
+        let path = input_set.path(solver.year(), solver.day());
+        lines.push(format!(
+            "    - {}: {}",
+            input_set.name, input_set.description
+        ));
+        lines.push(format!("        file: {}", describe_file(&path)));
+        for part in [Part::Part1, Part::Part2] {
+            match manifest.expected(solver.day(), input_set.name, part) {
+                None => lines.push(format!("        {}: unknown", part)),
+                Some(Answer::Text(text)) if text.contains('\n') => {
+                    lines.push(format!("        {}:", part));
+                    lines.extend(text.lines().map(|line| format!("            {}", line)));
+                }
+                Some(answer) => lines.push(format!("        {}: {}", part, answer)),
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// File of an input, the encrypted file when the input is only found encrypted
+fn describe_file(path: &str) -> String {
+    if input_dir::is_encrypted(path) {
+        format!("{} (encrypted)", input_key::encrypted_path(path))
+    } else if input_dir::exists(path) {
+        path.to_string()
+    } else {
+        format!("{} (missing)", path)
+    }
+}
+
+impl fmt::Display for InputSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Year;
+
+    #[test]
+    fn input_sets_are_read_from_the_inputs_folder() {
+        assert_eq!(InputSet::REAL.file_name(9), "input-09.txt");
+        assert_eq!(InputSet::EXAMPLE.file_name(9), "input-09-example.txt");
+        assert!(InputSet::EXAMPLE
+            .path(2022, 9)
+            .ends_with("2022/input-09-example.txt"));
+    }
+
+    #[test]
+    fn only_declared_input_sets_are_found() {
+        let rope_bridge = Year::get(2022).unwrap().find_solver("rope-bridge").unwrap();
+        assert_eq!(
+            InputSet::find(rope_bridge, "larger-example")
+                .unwrap()
+                .file_name(9),
+            "input-09-larger-example.txt"
+        );
+        let error = InputSet::find(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            "larger-example",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Unknown input set `larger-example` for exercise callories, expected one of `example`, `real`"
+        );
+    }
+
+    #[test]
+    fn input_sets_declared_by_no_solver_are_refused() {
+        let solvers = Year::get(2022).unwrap().solvers;
+        assert!(check_declared(solvers, "larger-example").is_ok());
+        assert_eq!(
+            check_declared(solvers, "nope").unwrap_err().to_string(),
+            "Unknown input set `nope`, expected one of `example`, `larger-example`, `real`"
+        );
+    }
+
+    #[test]
+    fn input_sets_are_listed_with_their_expected_answers() {
+        let manifest = Manifest::try_from(
+            r#"{ "9": { "example": { "part_1": 13, "part_2": 1 }, "larger-example": { "part_2": 36 } } }"#,
+        )
+        .unwrap();
+        let input_dir = InputDir::current();
+        assert_eq!(
+            format_input_sets(
+                Year::get(2022).unwrap().find_solver("rope-bridge").unwrap(),
+                &manifest
+            ),
+            format!(
+                "Input sets of rope-bridge (day 9 of 2022):
+    - example: example of the puzzle statement
+        file: {}
+        part_1: 13
+        part_2: 1
+    - larger-example: larger example of part 2, where the tail moves
+        file: {}
+        part_1: unknown
+        part_2: 36
+    - real: puzzle input
+        file: {}
+        part_1: unknown
+        part_2: unknown",
+                input_dir.path("2022/input-09-example.txt"),
+                input_dir.path("2022/input-09-larger-example.txt"),
+                describe_file(&input_dir.path("2022/input-09.txt"))
+            )
+        );
+    }
+
+    #[test]
+    fn input_files_are_described_as_found() {
+        let dir = std::env::temp_dir().join(format!("advent-of-code-sets-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("input-01.txt"), "1000\n").unwrap();
+        std::fs::write(dir.join("input-02.txt.enc"), "").unwrap();
+        let input_dir = InputDir::Directory(dir.clone());
+
+        let path = input_dir.path("input-01.txt");
+        assert_eq!(describe_file(&path), path);
+        assert_eq!(
+            describe_file(&input_dir.path("input-02.txt")),
+            format!("{} (encrypted)", input_dir.path("input-02.txt.enc"))
+        );
+        let path = input_dir.path("input-03.txt");
+        assert_eq!(describe_file(&path), format!("{} (missing)", path));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,449 +1,1043 @@
-mod assignment;
-mod beacon_exclusion_zone;
-mod boiling_boulders;
-mod callories;
-mod cathod_ray_tube;
-mod directory;
-mod distress_signal;
-mod elf_crates;
-mod hill_climbing;
-mod marker;
-mod monkey_in_the_middle;
-mod proboscidea_volcanium;
-mod pyroclastic_flow;
-mod regolith_reservoir;
-mod rock_paper_scissors;
-mod rope_bridge;
-mod rucksacks;
-mod tree_house;
+mod bench;
+mod cache;
+mod cancel;
+mod config;
+mod diagnostic;
+mod error;
+mod input_dir;
+mod input_key;
+mod input_set;
+pub mod log;
+mod manifest;
+mod params;
+mod runner;
+mod scaffold;
+mod serve;
+mod solver;
+mod submit;
+mod tui;
+mod verify;
+mod watch;
+mod website;
+pub mod year2022;
+
+pub use cancel::CancelToken;
+pub use error::{Error, ParseError};
+pub use input_set::InputSet;
+pub use params::{Param, Params};
+pub use solver::{Answer, Input, Puzzle, Solver, Year, YEARS};
+
+use std::{fmt, time::Duration};
 
 pub enum Command {
-    Help,
+    Help {
+        year: &'static Year,
+    },
     Exercise(Exercise),
+    All {
+        year: &'static Year,
+        input_set: String,
+        format: OutputFormat,
+        /// Number of runs done in parallel
+        jobs: usize,
+        timeout: Option<Duration>,
+        /// Solve every part instead of taking the cached answers
+        no_cache: bool,
+    },
+    Bench {
+        solver: &'static dyn Solver,
+        part: Part,
+        input_set: InputSet,
+        params: Params,
+        runs: usize,
+        warmup: usize,
+        save_baseline: bool,
+    },
+    Verify {
+        solvers: Vec<&'static dyn Solver>,
+        input_set: Option<String>,
+        manifest_path: String,
+        jobs: usize,
+        timeout: Option<Duration>,
+    },
+    ListInputs {
+        solver: &'static dyn Solver,
+        manifest_path: String,
+    },
+    New {
+        /// Year of the new day, which may not have any solution yet
+        year: u16,
+        day: u8,
+        names: scaffold::DayNames,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+    },
+    EncryptInputs,
+    ClearCache,
+    Serve {
+        year: &'static Year,
+        port: u16,
+        /// Number of requests handled at the same time
+        jobs: usize,
+        /// Time limit of each solve
+        timeout: Duration,
+    },
+    Tui {
+        year: &'static Year,
+    },
+    Submit {
+        solver: &'static dyn Solver,
+        part: Part,
+        /// Answer given on the command line instead of solving the real input
+        answer: Option<String>,
+    },
 }
 
 impl Command {
-    pub fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn run(&self) -> Result<(), Error> {
         match self {
-            Command::Help => {
+            Command::Help { year } => {
+                let exercise_list = year
+                    .solvers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, solver)| {
+                        let separator = if i + 1 == year.solvers.len() {
+                            "."
+                        } else {
+                            ","
+                        };
+                        let other_names = solver
+                            .names()
+                            .into_iter()
+                            .skip(1)
+                            .filter(|n| *n != solver.name())
+                            .map(|n| format!("`{}`", n))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        let mut line = format!(
+                            "    - {:>2}. {}, also {}{}",
+                            solver.day(),
+                            solver.name(),
+                            other_names,
+                            separator
+                        );
+                        for param in solver.params() {
+                            line.push_str(&format!(
+                                "\n        --param {}=<value>: {}",
+                                param.name, param.description
+                            ));
+                        }
+                        line
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                let years = YEARS
+                    .iter()
+                    .map(|y| y.year.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let year = year.year;
                 println!(
-                    "Advent of code, edition 2022
+                    "Advent of code, edition {year}
 
-Website: https://adventofcode.com/2022
+Website: https://adventofcode.com/{year}
+
+Available years: {years}
 
 List exercises and help: cargo run help
 
-Usage: cargo run [exercise] [part] [ARGS]...
-
-Exercise list (in the ascending order):
-    - callories,
-    - rock-paper-scissors,
-    - rucksack,
-    - assignement,
-    - elf-creates,
-    - marker,
-    - directory,
-    - tree-house,
-    - rope-bridge,
-    - cathod-ray-tube,
-    - monkey-in-the-middle,
-    - hill-climbing,
-    - distress-signals,
-    - regolith-reservoir,
-    - beacon-exclusion-zone,
-    - proboscidea-volcanium,
-    - pyroclastic-flow,
-    - boiling-boulders.
+Usage: cargo run [--year <year>] [exercise] [part] [ARGS]...
+       cargo run [exercise] --list-inputs
+       cargo run all [ARGS]...
+       cargo run verify [exercise] [ARGS]...
+       cargo run bench [exercise] [part] [ARGS]...
+       cargo run new [day] [name]
+       cargo run fetch [day|exercise]
+       cargo run encrypt-inputs
+       cargo run cache clear
+       cargo run serve [--port N] [--jobs N] [--timeout <duration>]
+       cargo run tui
+       cargo run submit [exercise] [part] [--answer <answer>]
+
+Exercise list (in the ascending order), an exercise is chosen by its day, its name, its module or its title:
+{exercise_list}
 
 Part:
     - part_1,
         Run part 1 of the exercise
     - part_2
         Run part 2 of the exercise
+    - both
+        Run both parts of the exercise, the input being parsed once
 
 Args:
+    --year <year>
+        Year of the exercises, for every command, can be given anywhere on the command line, defaults to the latest
+        year
+    --input-set <name>
+        Run the exercise on a named input of the day instead of the official input, `--list-inputs` shows the input
+        sets of an exercise with their expected answers
     -ex, --example
-        Run the exercise using exercise input instead of official input
+        Run the exercise using exercise input instead of official input, short for `--input-set example`
+    --input <path>
+        Run the exercise using the input file at the given path, `-` reads the input from stdin
+    --format <text|json>
+        Output format, `json` writes one JSON object per run on stdout, with the day, the part, the input path,
+        the answer, the elapsed time and the error if any
+    --param <name>=<value>
+        Override a constant of the puzzle, e.g. the number of knots of the rope, can be repeated,
+        the parameters of each exercise are listed with the exercise
+    --answer <answer>
+        Answer submitted by `submit` instead of the answer of the exercise, e.g. the letters drawn by the CRT
+    --watch
+        Run the exercise again whenever its input file changes, showing the answer, the time of the run and whether
+        it is the expected answer of the manifest
+    --all-errors
+        Report every malformed part of the input instead of stopping at the first one
+    --manifest <path>
+        Answer manifest used by `verify`, `--list-inputs` and `--watch`, defaults to `answers/<year>.json`
+    --jobs <N>
+        Number of runs done in parallel by `all` and `verify`, defaults to 1, with more than one job the log of each
+        run is shown once every run is done, in the order of the results, for `serve` the number of requests handled at the same time
+    --timeout <duration>
+        Stop each run after the duration, e.g. `500ms`, `30s` or `2m`, the run fails with the progress made so far,
+        defaults to 60s for `serve`
+    --port <N>
+        Port of the local host on which `serve` listens, defaults to 8080
+    --no-cache
+        Solve every part with `all` instead of taking the answers cached by the previous runs, which are kept in
+        `target/cache` of the crate by day, part, parameters, input and solver version, `cache clear` removes them
+    --runs <N>, --warmup <M>
+        Number of measured runs and of warmup runs of `bench`, defaults to 10 and 1
+    --save-baseline
+        Save the result of `bench` as the new baseline, the first run of a benchmark is always saved
+    -v, -vv, --verbose
+        Show the debug output of the exercises on stderr, `-vv` also shows the trace output
+    -q, --quiet
+        Only show errors on stderr, the log level can also be set with the `AOC_LOG` environment variable
+        (`off`, `error`, `warn`, `info`, `debug` or `trace`)
     -h, --help
         List exercises and help
+
+Environment:
+    AOC_INPUT_DIR
+        Directory of the input files, defaults to the `input_dir` of the configuration file, then to the inputs
+        embedded in the binary by the `embed-inputs` feature, then to the `inputs` folder of the crate
+    AOC_CONFIG
+        Path of the JSON configuration file, defaults to `~/.config/advent-of-code/config.json`
+    AOC_SESSION
+        Session token used by `fetch` and `submit`, the `session` cookie of a browser logged in on the website, defaults to the
+        `session` of the configuration file
+    AOC_BASE_URL
+        Address of the website used by `fetch` and `submit`, defaults to the `base_url` of the configuration file, then to
+        https://adventofcode.com
+    AOC_INPUT_KEY
+        Key of the encrypted inputs as 64 hexadecimal digits, defaults to the content of the `key_file` of the
+        configuration file, then of `input.key` next to the configuration file
                 "
                 );
                 Ok(())
             }
             Command::Exercise(exercise) => exercise.run(),
+            Command::All {
+                year,
+                input_set,
+                format,
+                jobs,
+                timeout,
+                no_cache,
+            } => {
+                let cache = (!no_cache).then(cache::ResultCache::current);
+                let reports =
+                    runner::run_all(year.solvers, input_set, *jobs, *timeout, cache.as_ref());
+                for line in reports.iter().flat_map(|r| &r.log) {
+                    eprintln!("{}", line);
+                }
+                match format {
+                    OutputFormat::Text => println!("{}", runner::format_table(&reports)),
+                    OutputFormat::Json => {
+                        for report in &reports {
+                            println!("{}", report.to_json());
+                        }
+                    }
+                }
+
+                let failures = reports.iter().filter(|r| !r.is_success()).count();
+                if failures > 0 {
+                    return Err(Error::RunsFailed {
+                        failed: failures,
+                        total: reports.len(),
+                    });
+                }
+                Ok(())
+            }
+            Command::Bench {
+                solver,
+                part,
+                input_set,
+                params,
+                runs,
+                warmup,
+                save_baseline,
+            } => {
+                let report = bench::bench(*solver, *part, *input_set, params, *runs, *warmup)?;
+                let baseline = report.load_baseline()?;
+                println!("{}", bench::format_report(&report, baseline.as_ref()));
+
+                if baseline.is_none() || *save_baseline {
+                    report.save_baseline()?;
+                    println!("Baseline saved to {}", report.baseline_path());
+                }
+                Ok(())
+            }
+            Command::Verify {
+                solvers,
+                input_set,
+                manifest_path,
+                jobs,
+                timeout,
+            } => {
+                let manifest = manifest::Manifest::load(manifest_path)?;
+                let verifications =
+                    verify::verify(solvers, &manifest, input_set.as_deref(), *jobs, *timeout);
+                for line in verifications.iter().flat_map(|v| &v.log) {
+                    eprintln!("{}", line);
+                }
+                println!("{}", verify::format_report(&verifications));
+
+                let failures = verifications
+                    .iter()
+                    .filter(|v| matches!(v.verdict, verify::Verdict::Fail(_)))
+                    .count();
+                if failures > 0 {
+                    return Err(Error::RunsFailed {
+                        failed: failures,
+                        total: verifications.len(),
+                    });
+                }
+                Ok(())
+            }
+            Command::ListInputs {
+                solver,
+                manifest_path,
+            } => {
+                let manifest = manifest::Manifest::load(manifest_path)?;
+                println!("{}", input_set::format_input_sets(*solver, &manifest));
+                Ok(())
+            }
+            Command::New { year, day, names } => {
+                let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+                let changed_files = scaffold::scaffold(root, *year, *day, names)?;
+                println!("Day {} of {} generated as `{}`:", day, year, names.name);
+                for file in changed_files {
+                    println!("    {}", file);
+                }
+                println!(
+                    "Fill the example input, fetch the real input with `cargo run fetch {}` then encrypt it with \
+                     `cargo run encrypt-inputs`, implement both parts, then add the expected answers to {}",
+                    day,
+                    manifest::default_path(*year)
+                );
+                Ok(())
+            }
+            Command::Submit {
+                solver,
+                part,
+                answer,
+            } => {
+                let config = config::Config::load()?;
+                let client = website::Client::from_env(&config)?;
+                let answer = match answer {
+                    Some(answer) => answer.clone(),
+                    None => {
+                        let input = Input::read(
+                            &InputSet::REAL.path(solver.year(), solver.day()),
+                            InputSet::REAL,
+                        )?;
+                        match solver.solve(part, &input)? {
+                            Answer::Text(text) if text.contains('\n') => {
+                                println!("{}", text);
+                                return Err(Error::usage(
+                                    "The answer is a drawing, give the letters it shows with `--answer <letters>`",
+                                ));
+                            }
+                            answer => answer.to_string(),
+                        }
+                    }
+                };
+
+                let mut history = submit::History::load(submit::History::default_path(&config)?)?;
+                history.check(solver.year(), solver.day(), *part, &answer, submit::now())?;
+                println!(
+                    "Submitting {} for day {} of {} {}",
+                    answer,
+                    solver.day(),
+                    solver.year(),
+                    part
+                );
+                let submission =
+                    client.submit_answer(solver.year(), solver.day(), *part, &answer)?;
+                let outcome = submission.outcome;
+                let time = submit::now();
+                history.record(submit::Attempt {
+                    year: solver.year(),
+                    day: solver.day(),
+                    part: *part,
+                    answer,
+                    outcome: outcome.clone(),
+                    time,
+                    wait_until: submission.lockout.map(|lockout| time + lockout.as_secs()),
+                })?;
+                println!("{}", outcome);
+                match outcome {
+                    submit::Outcome::Right => Ok(()),
+                    _ => Err(Error::RunsFailed {
+                        failed: 1,
+                        total: 1,
+                    }),
+                }
+            }
+            Command::Fetch { year, day } => {
+                let client = website::Client::from_env(&config::Config::load()?)?;
+                match website::fetch(&client, *year, *day, input_dir::InputDir::current())? {
+                    website::Fetched::Downloaded(path) => {
+                        println!("Input of day {} of {} downloaded to {}", day, year, path)
+                    }
+                    website::Fetched::Cached(path) => {
+                        println!(
+                            "Input of day {} of {} already cached in {}",
+                            day, year, path
+                        )
+                    }
+                }
+                Ok(())
+            }
+            Command::EncryptInputs => {
+                let key = match input_key::InputKey::current() {
+                    Some(key) => key.clone(),
+                    None => {
+                        let path = input_key::InputKey::default_path(&config::Config::load()?)
+                            .ok_or(Error::usage(format!(
+                                "Unknown location of the input key, set `{}` or the `key_file` setting of the configuration file",
+                                input_key::KEY_ENV_VAR
+                            )))?;
+                        let key = input_key::InputKey::generate();
+                        key.save(&path)?;
+                        println!(
+                            "New input key saved to {}, keep it out of the repository",
+                            path.display()
+                        );
+                        key
+                    }
+                };
+                let encrypted = input_dir::encrypt_inputs(input_dir::InputDir::current(), &key)?;
+                for path in &encrypted {
+                    println!("Encrypted {}", path);
+                }
+                println!("{} inputs encrypted", encrypted.len());
+                Ok(())
+            }
+            Command::ClearCache => {
+                let removed = cache::ResultCache::current().clear()?;
+                println!("{} cached answers removed", removed);
+                Ok(())
+            }
+            Command::Serve {
+                year,
+                port,
+                jobs,
+                timeout,
+            } => serve::serve(year, *port, *jobs, *timeout),
+            Command::Tui { year } => tui::run(year),
         }
     }
 }
 
 impl TryFrom<&Vec<String>> for Command {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(args: &Vec<String>) -> Result<Self, Self::Error> {
-        if args.len() == 0 {
-            return Err(
-                format!(
-                    "Invalid number of arguments, expected command as `cargo run <exercise name> <part (part_1 or part_2)>`, got no arguments",
-                ).into()
-            );
+        let (chosen_year, args) = take_year(args)?;
+        let args = &args;
+        // Only `new` accepts a year without solutions, the other commands need the solutions of the year
+        let year = || match chosen_year {
+            Some(year) => Year::get(year),
+            None => Ok(Year::latest()),
+        };
+
+        // Without a command, e.g. `cargo run -- --year 2022`, only the program name is left
+        if args.len() < 2 {
+            return Ok(Command::Help { year: year()? });
         }
 
         let help_cmd = "help".to_string();
         let short_help = "-h".to_string();
         let long_help = "--help".to_string();
         if args.contains(&long_help) | args.contains(&short_help) {
-            return Ok(Command::Help);
+            return Ok(Command::Help { year: year()? });
         }
 
         if args[1] == help_cmd {
-            return Ok(Command::Help);
+            return Ok(Command::Help { year: year()? });
+        }
+
+        if args[1] == "all" {
+            let input_set = input_set_name(&args[2..])?.unwrap_or(InputSet::REAL.name.to_string());
+            input_set::check_declared(year()?.solvers, &input_set)?;
+            let format = OutputFormat::from_args(&args[2..])?;
+            return Ok(Command::All {
+                year: year()?,
+                input_set,
+                format,
+                jobs: parse_jobs(&args[2..])?,
+                timeout: parse_timeout(&args[2..])?,
+                no_cache: has_flag(&args[2..], &["--no-cache"]),
+            });
+        }
+
+        if args[1] == "cache" {
+            return match args.get(2).map(|a| a.as_str()) {
+                Some("clear") => Ok(Command::ClearCache),
+                other => Err(Error::usage(format!(
+                    "Unknown cache command, expected `cargo run cache clear`, got {}",
+                    other.unwrap_or("no arguments")
+                ))),
+            };
+        }
+
+        if args[1] == "verify" {
+            let solvers = match args.get(2).filter(|a| !a.starts_with('-')) {
+                None => year()?.solvers.to_vec(),
+                Some(name) => vec![year()?.solver_named(name)?],
+            };
+            let input_set = input_set_name(&args[2..])?;
+            match (&input_set, &solvers[..]) {
+                (Some(name), [solver]) => {
+                    InputSet::find(*solver, name)?;
+                }
+                (Some(name), _) => input_set::check_declared(&solvers, name)?,
+                (None, _) => {}
+            }
+            let manifest_path = flag_value(&args[2..], "--manifest")?
+                .unwrap_or(manifest::default_path(year()?.year));
+            return Ok(Command::Verify {
+                solvers,
+                input_set,
+                manifest_path,
+                jobs: parse_jobs(&args[2..])?,
+                timeout: parse_timeout(&args[2..])?,
+            });
+        }
+
+        if args[1] == "bench" {
+            // Reuse the exercise parsing, the benchmarked exercise is given as for a single run
+            let exercise = Exercise::parse(year()?, &args[1..])?;
+            // The options of a single run which do not change what is measured are refused rather than ignored
+            if let Some(option) = args[4..].iter().find(|a| {
+                [
+                    "--input",
+                    "--format",
+                    "--all-errors",
+                    "--watch",
+                    "--manifest",
+                    "--timeout",
+                ]
+                .contains(&a.as_str())
+            }) {
+                return Err(Error::usage(format!(
+                    "Invalid option for `bench`, `{}` does not apply to a benchmark",
+                    option
+                )));
+            }
+            let [part] = exercise.parts[..] else {
+                return Err(Error::usage(
+                    "Invalid part, `bench` measures a single part, please choose either `part_1` or `part_2`",
+                ));
+            };
+            let runs = parse_count(&args[4..], "--runs")?.unwrap_or(10);
+            let warmup = parse_count(&args[4..], "--warmup")?.unwrap_or(1);
+            return Ok(Command::Bench {
+                solver: exercise.solver,
+                part,
+                input_set: exercise.input_set,
+                params: exercise.params,
+                runs,
+                warmup,
+                save_baseline: has_flag(&args[4..], &["--save-baseline"]),
+            });
+        }
+
+        if args[1] == "fetch" {
+            let Some(day_or_name) = args.get(2) else {
+                return Err(Error::usage(
+                    "Invalid number of arguments, expected command as `cargo run fetch <day>`",
+                ));
+            };
+            let day = match day_or_name.parse::<u8>() {
+                Ok(day) if (1..=25).contains(&day) => day,
+                Ok(day) => {
+                    return Err(Error::usage(format!(
+                        "Invalid day, expected a number from 1 to 25, got {}",
+                        day
+                    )))
+                }
+                Err(_) => year()?.solver_named(day_or_name)?.day(),
+            };
+            return Ok(Command::Fetch {
+                year: chosen_year.unwrap_or(Year::latest().year),
+                day,
+            });
+        }
+
+        if args[1] == "encrypt-inputs" {
+            return Ok(Command::EncryptInputs);
+        }
+
+        if args[1] == "serve" {
+            let port = match flag_value(&args[2..], "--port")? {
+                None => 8080,
+                Some(value) => value.parse::<u16>().map_err(|_| {
+                    Error::usage(format!(
+                        "Invalid value for `--port`, expected a port number, got {}",
+                        value
+                    ))
+                })?,
+            };
+            return Ok(Command::Serve {
+                year: year()?,
+                port,
+                jobs: parse_jobs(&args[2..])?,
+                timeout: parse_timeout(&args[2..])?.unwrap_or(serve::DEFAULT_TIMEOUT),
+            });
+        }
+
+        if args[1] == "tui" {
+            return Ok(Command::Tui { year: year()? });
+        }
+
+        if args[1] == "submit" {
+            if args.len() < 4 {
+                return Err(Error::usage(
+                    "Invalid number of arguments, expected command as `cargo run submit <exercise> <part>`",
+                ));
+            }
+            return Ok(Command::Submit {
+                solver: year()?.solver_named(&args[2])?,
+                part: Part::try_from(args[3].as_str())?,
+                answer: flag_value(&args[4..], "--answer")?,
+            });
+        }
+
+        if args[1] == "new" {
+            if args.len() < 4 {
+                return Err(Error::usage(format!(
+                    "Invalid number of arguments, expected command as `cargo run new <day> <name>`, got arguments {:?}",
+                    args
+                )));
+            }
+            let day = args[2].parse::<u8>().map_err(|_| {
+                Error::usage(format!(
+                    "Invalid day, expected a number from 1 to 25, got {}",
+                    args[2]
+                ))
+            })?;
+            let names = scaffold::DayNames::try_from(args[3].as_str())?;
+            return Ok(Command::New {
+                year: chosen_year.unwrap_or(Year::latest().year),
+                day,
+                names,
+            });
+        }
+
+        if args.len() > 2 && has_flag(&args[2..], &["--list-inputs"]) {
+            let solver = year()?.solver_named(&args[1])?;
+            let manifest_path = flag_value(&args[2..], "--manifest")?
+                .unwrap_or(manifest::default_path(solver.year()));
+            return Ok(Command::ListInputs {
+                solver,
+                manifest_path,
+            });
+        }
+
+        return Exercise::parse(year()?, args).map(Command::Exercise);
+    }
+}
+
+pub struct Exercise {
+    solver: &'static dyn Solver,
+    /// Parts to run, both parts sharing the parsed input when they are run together
+    parts: Vec<Part>,
+    input_set: InputSet,
+    /// Input file given with `--input`, read instead of the file of the input set
+    input_path: Option<String>,
+    format: OutputFormat,
+    all_errors: bool,
+    params: Params,
+    /// Re-run the exercise whenever its input file changes
+    watch: bool,
+    /// Manifest of the expected answers shown by the watch mode
+    manifest_path: String,
+    /// Time limit of each run
+    timeout: Option<Duration>,
+}
+
+fn has_flag(args: &[String], names: &[&str]) -> bool {
+    args.iter().any(|a| names.contains(&a.as_str()))
+}
+
+fn flag_value(args: &[String], name: &str) -> Result<Option<String>, Error> {
+    match args.iter().position(|a| a == name) {
+        None => Ok(None),
+        Some(i) => Ok(Some(
+            args.get(i + 1)
+                .ok_or(Error::usage(format!("Missing value for `{}`", name)))?
+                .to_string(),
+        )),
+    }
+}
+
+/// Every value given to a flag which can be repeated, e.g. `--param knots=3 --param rocks=10`
+fn flag_values(args: &[String], name: &str) -> Result<Vec<String>, Error> {
+    let mut values = vec![];
+    for (i, arg) in args.iter().enumerate() {
+        if arg == name {
+            values.push(
+                args.get(i + 1)
+                    .ok_or(Error::usage(format!("Missing value for `{}`", name)))?
+                    .to_string(),
+            );
         }
+    }
+    Ok(values)
+}
+
+/// Name of the input set chosen with `--input-set <name>`, `-ex` and `--example` being short for `--input-set example`
+fn input_set_name(args: &[String]) -> Result<Option<String>, Error> {
+    let name = flag_value(args, "--input-set")?;
+    if !has_flag(args, &["-ex", "--example"]) {
+        return Ok(name);
+    }
+    match name {
+        Some(name) if name != InputSet::EXAMPLE.name => Err(Error::usage(format!(
+            "Conflicting input sets, `--example` cannot be combined with `--input-set {}`",
+            name
+        ))),
+        _ => Ok(Some(InputSet::EXAMPLE.name.to_string())),
+    }
+}
+
+fn parse_count(args: &[String], name: &str) -> Result<Option<usize>, Error> {
+    flag_value(args, name)?
+        .map(|value| {
+            value.parse::<usize>().map_err(|_| {
+                Error::usage(format!(
+                    "Invalid value for `{}`, expected a number, got {}",
+                    name, value
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Time limit of each run given with `--timeout`, if any
+fn parse_timeout(args: &[String]) -> Result<Option<Duration>, Error> {
+    flag_value(args, "--timeout")?
+        .map(|value| cancel::parse_duration(&value))
+        .transpose()
+}
 
-        return Exercise::try_from(args).and_then(|ex| Ok(Command::Exercise(ex)));
+/// Number of runs done in parallel by `all` and `verify`, one by default
+fn parse_jobs(args: &[String]) -> Result<usize, Error> {
+    match parse_count(args, "--jobs")? {
+        None => Ok(1),
+        Some(0) => Err(Error::usage(
+            "Invalid value for `--jobs`, expected at least one job",
+        )),
+        Some(jobs) => Ok(jobs),
     }
 }
 
-pub enum Exercise {
-    Callories(Part, bool),
-    RockPaperScissors(Part, bool),
-    Rucksack(Part, bool),
-    Assignement(Part, bool),
-    ElfCrates(Part, bool),
-    Marker(Part, bool),
-    Directory(Part, bool),
-    TreeHouse(Part, bool),
-    RopeBridge(Part, bool),
-    CathodRayTube(Part, bool),
-    MonkeyInTheMiddle(Part, bool),
-    HillClimbing(Part, bool),
-    DistressSignals(Part, bool),
-    RegolithReservoir(Part, bool),
-    BeaconExclusionZone(Part, bool),
-    ProboscideaVolcanium(Part, bool),
-    PyroclasticFlow(Part, bool),
-    BoilingBoulders(Part, bool),
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    fn from_args(args: &[String]) -> Result<Self, Error> {
+        match flag_value(args, "--format")?.as_deref() {
+            None | Some("text") => Ok(OutputFormat::Text),
+            Some("json") => Ok(OutputFormat::Json),
+            Some(other) => Err(Error::usage(format!(
+                "Unknown output format, please choose either `text` or `json`, got {}",
+                other
+            ))),
+        }
+    }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Part {
     Part1,
     Part2,
 }
 
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::Part1 => write!(f, "part_1"),
+            Part::Part2 => write!(f, "part_2"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "part_1" => Ok(Part::Part1),
+            "part_2" => Ok(Part::Part2),
+            other => Err(Error::usage(format!(
+                "Unknown part chose, please choose either `part_1` or `part_2`, got {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Year chosen with `--year <year>` anywhere in the arguments, and the other arguments
+fn take_year(args: &[String]) -> Result<(Option<u16>, Vec<String>), Error> {
+    let Some(i) = args.iter().position(|a| a == "--year") else {
+        return Ok((None, args.to_vec()));
+    };
+    let value = args
+        .get(i + 1)
+        .ok_or(Error::usage("Missing value for `--year`"))?;
+    let year = value
+        .parse::<u16>()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| {
+            Error::usage(format!(
+                "Invalid year, expected a year of the Advent of code from 2015, got {}",
+                value
+            ))
+        })?;
+    let mut args = args.to_vec();
+    args.drain(i..i + 2);
+    Ok((Some(year), args))
+}
+
 impl TryFrom<&Vec<String>> for Exercise {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
-    fn try_from(args: &Vec<String>) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Parse the exercise of the year chosen with `--year`, or else of the latest year
+    fn try_from(args: &Vec<String>) -> Result<Self, Error> {
+        let (year, args) = take_year(args)?;
+        let year = match year {
+            Some(year) => Year::get(year)?,
+            None => Year::latest(),
+        };
+        Exercise::parse(year, &args)
+    }
+}
+
+impl Exercise {
+    fn parse(year: &Year, args: &[String]) -> Result<Self, Error> {
         if args.len() < 3 {
-            return Err(
-                format!(
-                    "Invalid number of arguments, expected command as `cargo run <exercise name> <part>`, got arguments {:?}",
-                    args
-                ).into()
-            );
+            return Err(Error::usage(format!(
+                "Invalid number of arguments, expected command as `cargo run <exercise name> <part>`, got arguments {:?}",
+                args
+            )));
         }
         let exercise_name = args[1].as_str();
         let exercise_part = args[2].as_str();
-        let part = match exercise_part {
-            "part_1" => Part::Part1,
-            "part_2" => Part::Part2,
-            other => {
-                return Err(format!(
-                    "Unknown part chose, please choose either `part_1` or `part_2`, got {}",
-                    other
-                )
-                .into());
-            }
+        let parts = match exercise_part {
+            "both" => vec![Part::Part1, Part::Part2],
+            other => vec![Part::try_from(other)?],
         };
 
-        let example_short = "-ex".to_string();
-        let example_long = "--example".to_string();
-        let use_example = args[3..].contains(&example_short) || args[3..].contains(&example_long);
-
-        match exercise_name {
-            "callories" => Ok(Exercise::Callories(part, use_example)),
-            "rock-paper-scissors" => Ok(Exercise::RockPaperScissors(part, use_example)),
-            "rucksack" => Ok(Exercise::Rucksack(part, use_example)),
-            "assignement" => Ok(Exercise::Assignement(part, use_example)),
-            "elf-crates" => Ok(Exercise::ElfCrates(part, use_example)),
-            "marker" => Ok(Exercise::Marker(part, use_example)),
-            "directory" => Ok(Exercise::Directory(part, use_example)),
-            "tree-house" => Ok(Exercise::TreeHouse(part, use_example)),
-            "rope-bridge" => Ok(Exercise::RopeBridge(part, use_example)),
-            "cathod-ray-tube" => Ok(Exercise::CathodRayTube(part, use_example)),
-            "monkey-in-the-middle" => Ok(Exercise::MonkeyInTheMiddle(part, use_example)),
-            "hill-climbing" => Ok(Exercise::HillClimbing(part, use_example)),
-            "distress-signals" => Ok(Exercise::DistressSignals(part, use_example)),
-            "regolith-reservoir" => Ok(Exercise::RegolithReservoir(part, use_example)),
-            "beacon-exclusion-zone" => Ok(Exercise::BeaconExclusionZone(part, use_example)),
-            "proboscidea-volcanium" => Ok(Exercise::ProboscideaVolcanium(part, use_example)),
-            "pyroclastic-flow" => Ok(Exercise::PyroclasticFlow(part, use_example)),
-            "boiling-boulders" => Ok(Exercise::BoilingBoulders(part, use_example)),
-            other => {
-                return Err(format!(
-                    "Unknown exercise chosen, please choose one of the available exercise, got {}",
-                    other
-                )
-                .into());
-            }
+        let input_set_name = input_set_name(&args[3..])?;
+
+        let input_path = flag_value(&args[3..], "--input")?;
+
+        let format = OutputFormat::from_args(&args[3..])?;
+
+        let all_errors = has_flag(&args[3..], &["--all-errors"]);
+
+        let solver = year.solver_named(exercise_name)?;
+
+        let input_set = match input_set_name {
+            Some(name) => InputSet::find(solver, &name)?,
+            None => InputSet::REAL,
+        };
+
+        let params = Params::parse(solver, &parts, &flag_values(&args[3..], "--param")?)?;
+
+        let watch = has_flag(&args[3..], &["--watch"]);
+        if watch && format == OutputFormat::Json {
+            return Err(Error::usage(
+                "`--watch` cannot be combined with `--format json`",
+            ));
         }
+
+        let manifest_path =
+            flag_value(&args[3..], "--manifest")?.unwrap_or(manifest::default_path(solver.year()));
+
+        Ok(Exercise {
+            solver,
+            parts,
+            input_set,
+            input_path,
+            format,
+            all_errors,
+            params,
+            watch,
+            manifest_path,
+            timeout: parse_timeout(&args[3..])?,
+        })
     }
-}
 
-impl Exercise {
-    fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
-        match self {
-            Exercise::Callories(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-01-example.txt"
-                } else {
-                    "inputs/input-01.txt"
-                };
-                let result = match part {
-                    Part::Part1 => callories::find_max_callories_on_single_elf(filename)?,
-                    Part::Part2 => callories::find_sum_of_maximums_callories(filename, 3)?,
-                };
-                println!("Got {}", result);
-            }
-            Exercise::RockPaperScissors(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-02-example.txt"
-                } else {
-                    "inputs/input-02.txt"
-                };
-                let result = match part {
-                    Part::Part1 => {
-                        rock_paper_scissors::compute_score_with_initial_strategy(filename)?
-                    }
-                    Part::Part2 => {
-                        rock_paper_scissors::compute_score_with_second_strategy(filename)?
-                    }
-                };
-                println!("Got {}", result);
-            }
-            Exercise::Rucksack(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-03-example.txt"
-                } else {
-                    "inputs/input-03.txt"
-                };
-                let result = match part {
-                    Part::Part1 => rucksacks::first_part::compute_priorities_sum(filename)?,
-                    Part::Part2 => rucksacks::second_part::compute_priorities_sum(filename)?,
-                };
-                println!("Got {}", result);
-            }
-            Exercise::Assignement(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-04-example.txt"
-                } else {
-                    "inputs/input-04.txt"
-                };
-                let result = match part {
-                    Part::Part1 => assignment::count_fully_contained_assignement_in_pair(filename)?,
-                    Part::Part2 => assignment::count_overlapping_assignement_in_pair(filename)?,
-                };
-                println!("Got {}", result);
-            }
-            Exercise::ElfCrates(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-05-example.txt"
-                } else {
-                    "inputs/input-05.txt"
-                };
-                let should_move_crate_one_at_the_time = match part {
-                    Part::Part1 => true,
-                    Part::Part2 => false,
-                };
-                let result = elf_crates::move_crates(filename, should_move_crate_one_at_the_time)?;
-                println!("Got {}", result);
-            }
-            Exercise::Marker(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-06-example.txt"
-                } else {
-                    "inputs/input-06.txt"
-                };
-                let target_length = match part {
-                    Part::Part1 => 4,
-                    Part::Part2 => 14,
-                };
-                let result = marker::find_start_of_packet_marker_index(filename, target_length)?;
-                println!("Got {}", result);
+    fn run(&self) -> Result<(), Error> {
+        let filename = self.input_path();
+        if self.watch {
+            return self.watch(&filename);
+        }
+        if self.format == OutputFormat::Json {
+            let reports = match &self.parts[..] {
+                [part] => vec![runner::run_part_with_input(
+                    self.solver,
+                    *part,
+                    &filename,
+                    self.input_set,
+                    &self.params,
+                    CancelToken::new(self.timeout),
+                )],
+                _ => runner::run_both_with_input(
+                    self.solver,
+                    &filename,
+                    self.input_set,
+                    &self.params,
+                    CancelToken::new(self.timeout),
+                ),
+            };
+            for report in &reports {
+                println!("{}", report.to_json());
             }
-            Exercise::Directory(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-07-example.txt"
-                } else {
-                    "inputs/input-07.txt"
-                };
-                let result = match part {
-                    Part::Part1 => directory::find_sum_of_small_diretories(filename)?,
-                    Part::Part2 => directory::find_smallest_dir_to_delete_for_update(filename)?,
-                };
-                println!("Got {}", result);
+            let failures = reports.iter().filter(|r| !r.is_success()).count();
+            if failures > 0 {
+                return Err(Error::RunsFailed {
+                    failed: failures,
+                    total: reports.len(),
+                });
             }
-            Exercise::TreeHouse(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-08-example.txt"
-                } else {
-                    "inputs/input-08.txt"
-                };
-                let result = match part {
-                    Part::Part1 => tree_house::count_visible_trees(filename)?,
-                    Part::Part2 => tree_house::find_highest_scenic_score(filename)?,
-                };
-                println!("Got {}", result);
+            return Ok(());
+        }
+
+        let answers = match self.solve(&filename) {
+            Ok(answers) => answers,
+            Err(error @ (Error::Parse(_) | Error::ParseErrors(_))) => {
+                eprintln!("{}", diagnostic::render(&error, self.all_errors));
+                return Err(Error::RunsFailed {
+                    failed: 1,
+                    total: 1,
+                });
             }
-            Exercise::RopeBridge(part, use_example) => {
-                let filename = match part {
-                    Part::Part1 => {
-                        if *use_example {
-                            "inputs/input-09-example.txt"
-                        } else {
-                            "inputs/input-09.txt"
-                        }
-                    }
-                    Part::Part2 => {
-                        if *use_example {
-                            "inputs/input-09-example-part-2.txt"
-                        } else {
-                            "inputs/input-09.txt"
+            Err(error) => return Err(error),
+        };
+        match &answers[..] {
+            [(_, answer)] => match answer {
+                Answer::Text(text) if text.contains('\n') => println!("Got \n{}", text),
+                other => println!("Got {}", other),
+            },
+            _ => {
+                for (part, answer) in answers {
+                    match answer {
+                        Answer::Text(text) if text.contains('\n') => {
+                            println!("{}:\n{}", part, text)
                         }
+                        other => println!("{}: {}", part, other),
                     }
-                };
-                let knots_number = match part {
-                    Part::Part1 => 2,
-                    Part::Part2 => 10,
-                };
-                let result = rope_bridge::count_distinct_tail_positions(filename, knots_number)?;
-                println!("Got {}", result);
-            }
-            Exercise::CathodRayTube(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-10-example.txt"
-                } else {
-                    "inputs/input-10.txt"
-                };
-                let result = match part {
-                    Part::Part1 => cathod_ray_tube::sum_signal_strengths(filename)?.to_string(),
-                    Part::Part2 => cathod_ray_tube::display_signal(filename)?,
-                };
-
-                println!("Got \n{}", result)
+                }
             }
-            Exercise::MonkeyInTheMiddle(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-11-example.txt"
-                } else {
-                    "inputs/input-11.txt"
-                };
-                let result = match part {
-                    Part::Part1 => monkey_in_the_middle::compute_monkey_business(filename)?,
-                    Part::Part2 => monkey_in_the_middle::compute_big_monkey_business(filename)?,
-                };
-
-                println!("Got {}", result)
-            }
-            Exercise::HillClimbing(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-12-example.txt"
-                } else {
-                    "inputs/input-12.txt"
-                };
-                let result = match part {
-                    Part::Part1 => hill_climbing::find_shortest_path(filename)?,
-                    Part::Part2 => {
-                        hill_climbing::find_shortest_path_from_any_lowest_point(filename)?
-                    }
-                };
+        }
+        Ok(())
+    }
 
-                println!("Got {}", result)
+    /// Run the exercise, then run it again on every change of its input file, until the process is interrupted
+    fn watch(&self, filename: &str) -> Result<(), Error> {
+        let mut watcher = watch::InputWatcher::new(filename)?;
+        loop {
+            println!(
+                "Running day {} {} on {}",
+                self.solver.day(),
+                self.solver.name(),
+                filename
+            );
+            let reports = match &self.parts[..] {
+                [part] => vec![runner::run_part_with_input(
+                    self.solver,
+                    *part,
+                    filename,
+                    self.input_set,
+                    &self.params,
+                    CancelToken::new(self.timeout),
+                )],
+                _ => runner::run_both_with_input(
+                    self.solver,
+                    filename,
+                    self.input_set,
+                    &self.params,
+                    CancelToken::new(self.timeout),
+                ),
+            };
+            // The manifest is read again on every run, so that an expected answer added meanwhile is used
+            let manifest = match (
+                &self.input_path,
+                manifest::Manifest::load(&self.manifest_path),
+            ) {
+                (None, Ok(manifest)) => Some(manifest),
+                (None, Err(e)) => {
+                    warn!("No expected answers, {}", e);
+                    None
+                }
+                // The answers of the manifest are those of the input set, not of a file given with `--input`
+                (Some(_), _) => None,
+            };
+            for report in &reports {
+                let expected = manifest
+                    .as_ref()
+                    .and_then(|m| m.expected(self.solver.day(), self.input_set.name, report.part));
+                println!("{}", watch::format_report(report, expected));
             }
-            Exercise::DistressSignals(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-13-example.txt"
-                } else {
-                    "inputs/input-13.txt"
-                };
-                let result = match part {
-                    Part::Part1 => distress_signal::sum_over_right_pair_indices(filename)?,
-                    Part::Part2 => distress_signal::find_decoder_key(filename)?,
-                };
+            println!(
+                "Watching {} for changes, press Ctrl-C to stop\n",
+                watcher.path()
+            );
+            watcher.wait_for_change();
+        }
+    }
 
-                println!("Got {}", result)
-            }
-            Exercise::RegolithReservoir(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-14-example.txt"
-                } else {
-                    "inputs/input-14.txt"
-                };
-                let result = match part {
-                    Part::Part1 => regolith_reservoir::find_number_of_resting_units_of_sand_before_falling_in_void(filename)?,
-                    Part::Part2 => regolith_reservoir::find_number_of_resting_units_of_sand_before_blocked(filename)?,
-                };
+    fn input_path(&self) -> String {
+        self.input_path
+            .clone()
+            .unwrap_or_else(|| self.input_set.path(self.solver.year(), self.solver.day()))
+    }
 
-                println!("Got {}", result)
-            }
-            Exercise::BeaconExclusionZone(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-15-example.txt"
-                } else {
-                    "inputs/input-15.txt"
-                };
-                let row = if *use_example { 10 } else { 2_000_000 };
-                let result = match part {
-                    Part::Part1 => beacon_exclusion_zone::find_number_of_covered_positions_in_row(
-                        filename, row,
-                    )?,
-                    Part::Part2 => {
-                        beacon_exclusion_zone::find_distress_beacon_tuning_frequency(filename)?
-                    }
-                };
-                println!("Got {}", result)
-            }
-            Exercise::ProboscideaVolcanium(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-16-example.txt"
-                } else {
-                    "inputs/input-16.txt"
-                };
-                let result = match part {
-                    Part::Part1 => {
-                        proboscidea_volcanium::find_most_released_pressure(filename, 30, 1)?
-                    }
-                    Part::Part2 => {
-                        proboscidea_volcanium::find_most_released_pressure(filename, 26, 2)?
-                    }
-                };
-                println!("Got {}", result)
-            }
-            Exercise::PyroclasticFlow(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-17-example.txt"
-                } else {
-                    "inputs/input-17.txt"
-                };
-                let result = match part {
-                    Part::Part1 => pyroclastic_flow::find_tower_height(filename, 2_022)?,
-                    Part::Part2 => {
-                        pyroclastic_flow::find_tower_height(filename, 1_000_000_000_000)?
-                    }
-                };
-                println!("Got {}", result)
+    fn solve(&self, filename: &str) -> Result<Vec<(Part, Answer)>, Error> {
+        let input = Input::read(filename, self.input_set)?
+            .with_params(self.params.clone())
+            .with_cancel(CancelToken::new(self.timeout));
+        match &self.parts[..] {
+            [part] => Ok(vec![(*part, self.solver.solve(part, &input)?)]),
+            _ => {
+                let (answer_1, answer_2) = self.solver.solve_both(&input)?;
+                Ok(vec![(Part::Part1, answer_1), (Part::Part2, answer_2)])
             }
-            Exercise::BoilingBoulders(part, use_example) => {
-                let filename = if *use_example {
-                    "inputs/input-18-example.txt"
-                } else {
-                    "inputs/input-18.txt"
-                };
-                let result = match part {
-                    Part::Part1 => boiling_boulders::derive_surface_area(filename, false)?,
-                    Part::Part2 => boiling_boulders::derive_surface_area(filename, true)?,
-                };
-                println!("Got {}", result)
-            }
-        };
-        Ok(())
+        }
     }
 }
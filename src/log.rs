@@ -0,0 +1,346 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    env, fmt,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crate::Error;
+
+/// Environment variable giving the log level when no verbosity flag is given, e.g. `AOC_LOG=debug`
+pub const LOG_ENV_VAR: &str = "AOC_LOG";
+
+/// Level of a log message, the messages of a level are shown when the current level is at least as verbose
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+const DEFAULT_LEVEL: Level = Level::Info;
+
+static LEVEL: AtomicU8 = AtomicU8::new(DEFAULT_LEVEL as u8);
+
+const LEVELS: [Level; 6] = [
+    Level::Off,
+    Level::Error,
+    Level::Warn,
+    Level::Info,
+    Level::Debug,
+    Level::Trace,
+];
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    LEVELS[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Whether the messages of the level are shown, to be checked before building an expensive message
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+/// Messages captured instead of being written on stderr, shared between the threads writing and reading them
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<String>>>,
+    /// Number of the last messages kept, every message being kept when there is none
+    capacity: Option<usize>,
+}
+
+impl LogBuffer {
+    pub fn new() -> Self {
+        LogBuffer::default()
+    }
+
+    /// Buffer keeping only the last `capacity` messages, the oldest ones being dropped
+    pub fn bounded(capacity: usize) -> Self {
+        LogBuffer {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity: Some(capacity),
+        }
+    }
+
+    /// Captured messages, in the order they were written
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Last `count` captured messages, in the order they were written
+    pub fn last_lines(&self, count: usize) -> Vec<String> {
+        let lines = self.lines.lock().unwrap();
+        lines
+            .iter()
+            .skip(lines.len().saturating_sub(count))
+            .cloned()
+            .collect()
+    }
+
+    pub fn push(&self, line: String) {
+        let mut lines = self.lines.lock().unwrap();
+        if self
+            .capacity
+            .is_some_and(|capacity| lines.len() >= capacity)
+        {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+thread_local! {
+    static CAPTURE: RefCell<Option<LogBuffer>> = const { RefCell::new(None) };
+}
+
+/// Capture the messages of the current thread in the buffer instead of writing them on stderr, `None` restores stderr.
+///
+/// Returns the buffer which was capturing the messages of the thread, if any.
+pub fn capture(buffer: Option<LogBuffer>) -> Option<LogBuffer> {
+    CAPTURE.with(|capture| capture.replace(buffer))
+}
+
+/// Call `f`, giving its result with the messages it logged on the current thread
+pub fn captured<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let buffer = LogBuffer::new();
+    let previous = capture(Some(buffer.clone()));
+    let result = f();
+    capture(previous);
+    (result, buffer.lines())
+}
+
+/// Call `f` as `captured` when `capture` is set, or else let it write its messages as they come, without any captured
+/// message
+pub fn captured_if<T>(capture: bool, f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    if capture {
+        captured(f)
+    } else {
+        (f(), vec![])
+    }
+}
+
+/// Write a message on stderr, or in the capture buffer of the thread, prefixed by its level and by the module it comes
+/// from.
+///
+/// Use the `error!`, `warn!`, `info!`, `debug!` and `trace!` macros rather than calling it directly.
+pub fn write(level: Level, module_path: &str, message: fmt::Arguments) {
+    let module = module_path
+        .split_once("::")
+        .map_or(module_path, |(_, module)| module);
+    let line = format!("[{:<5}] {}: {}", level, module, message);
+    CAPTURE.with(|capture| match &*capture.borrow() {
+        Some(buffer) => buffer.push(line),
+        None => eprintln!("{}", line),
+    });
+}
+
+/// Set the level from the verbosity flags of the command line, or else from the `AOC_LOG` environment variable.
+///
+/// Returns the arguments without the verbosity flags, so that they can appear anywhere in the command line.
+pub fn init(args: Vec<String>) -> Result<Vec<String>, Error> {
+    let env_level = env::var(LOG_ENV_VAR).ok();
+    let (level, args) = level_from(args, env_level.as_deref())?;
+    set_level(level);
+    Ok(args)
+}
+
+fn level_from(args: Vec<String>, env_level: Option<&str>) -> Result<(Level, Vec<String>), Error> {
+    let mut verbosity = 0;
+    let mut quiet = false;
+    let mut remaining_args = vec![];
+    for arg in args {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "-q" | "--quiet" => quiet = true,
+            _ => remaining_args.push(arg),
+        }
+    }
+
+    let level = match (quiet, verbosity) {
+        (true, 0) => Level::Error,
+        (true, _) => {
+            return Err(Error::usage(
+                "Invalid verbosity, `--quiet` cannot be combined with `--verbose`",
+            ))
+        }
+        (false, 0) => match env_level {
+            None | Some("") => DEFAULT_LEVEL,
+            Some(value) => Level::try_from(value)?,
+        },
+        (false, 1) => Level::Debug,
+        (false, _) => Level::Trace,
+    };
+    Ok((level, remaining_args))
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "OFF",
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+impl TryFrom<&str> for Level {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Error> {
+        match value.to_lowercase().as_str() {
+            "off" => Ok(Level::Off),
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(Error::usage(format!(
+                "Unknown log level in `{}`, please choose one of `off`, `error`, `warn`, `info`, `debug` or `trace`, got {}",
+                LOG_ENV_VAR, value
+            ))),
+        }
+    }
+}
+
+/// Log a message at the given level, the message is only formatted if the level is enabled
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn verbosity_flags_are_removed_from_the_arguments() {
+        let (level, remaining) =
+            level_from(args(&["aoc", "-v", "rope-bridge", "part_1", "-v"]), None).unwrap();
+        assert_eq!(level, Level::Trace);
+        assert_eq!(remaining, args(&["aoc", "rope-bridge", "part_1"]));
+
+        let (level, _) = level_from(args(&["aoc", "-vv"]), Some("error")).unwrap();
+        assert_eq!(level, Level::Trace);
+
+        let (level, _) = level_from(args(&["aoc", "--quiet"]), Some("trace")).unwrap();
+        assert_eq!(level, Level::Error);
+
+        assert!(level_from(args(&["aoc", "-q", "-v"]), None).is_err());
+    }
+
+    #[test]
+    fn messages_are_captured_per_thread() {
+        let buffer = LogBuffer::new();
+        let thread_buffer = buffer.clone();
+        std::thread::spawn(move || {
+            capture(Some(thread_buffer));
+            write(
+                Level::Warn,
+                "advent_of_code::year2022::rope_bridge",
+                format_args!("{} knots", 10),
+            );
+        })
+        .join()
+        .unwrap();
+        assert_eq!(
+            buffer.lines(),
+            vec!["[WARN ] year2022::rope_bridge: 10 knots".to_string()]
+        );
+
+        let (answer, lines) = captured(|| {
+            write(
+                Level::Info,
+                "advent_of_code::runner",
+                format_args!("solving"),
+            );
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(lines, vec!["[INFO ] runner: solving".to_string()]);
+
+        let buffer = LogBuffer::new();
+        capture(Some(buffer.clone()));
+        let (_, lines) = captured_if(false, || {
+            write(
+                Level::Info,
+                "advent_of_code::runner",
+                format_args!("streamed"),
+            )
+        });
+        capture(None);
+        assert!(lines.is_empty());
+        assert_eq!(buffer.lines(), vec!["[INFO ] runner: streamed".to_string()]);
+    }
+
+    #[test]
+    fn bounded_buffers_keep_the_last_messages() {
+        let buffer = LogBuffer::bounded(3);
+        for i in 0..5 {
+            buffer.push(i.to_string());
+        }
+        assert_eq!(buffer.lines(), vec!["2", "3", "4"]);
+        assert_eq!(buffer.last_lines(2), vec!["3", "4"]);
+        assert_eq!(buffer.last_lines(10), vec!["2", "3", "4"]);
+    }
+
+    #[test]
+    fn environment_variable_is_used_without_flags() {
+        assert_eq!(level_from(args(&["aoc"]), None).unwrap().0, Level::Info);
+        assert_eq!(
+            level_from(args(&["aoc"]), Some("DEBUG")).unwrap().0,
+            Level::Debug
+        );
+        assert_eq!(
+            level_from(args(&["aoc"]), Some("off")).unwrap().0,
+            Level::Off
+        );
+        assert!(level_from(args(&["aoc"]), Some("loud")).is_err());
+    }
+}
@@ -1,16 +1,24 @@
 use std::{env, process};
 
-use advent_of_code::Command;
+use advent_of_code::{log, Command, Error};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let args = log::init(env::args().collect()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing argumments {err}");
+        process::exit(1);
+    });
     let command = Command::try_from(&args).unwrap_or_else(|err| {
-        println!("Problem parsing argumments {err}");
+        eprintln!("Problem parsing argumments {err}");
         process::exit(1);
     });
 
-    if let Err(err) = command.run() {
-        println!("Problem while running the exercise {err}");
-        process::exit(1);
+    match command.run() {
+        Ok(()) => {}
+        // The error of a single run has already been reported
+        Err(Error::RunsFailed { total: 1, .. }) => process::exit(1),
+        Err(err) => {
+            eprintln!("Problem while running the exercise {err}");
+            process::exit(1);
+        }
     }
 }
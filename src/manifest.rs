@@ -0,0 +1,168 @@
+use std::{collections::HashMap, fs};
+
+use serde_json::Value;
+
+use crate::{
+    error::{parse_json, parse_value},
+    Answer, Error, Part,
+};
+
+/// Manifest of a year in the crate, found from any working directory
+pub fn default_path(year: u16) -> String {
+    format!("{}/answers/{}.json", env!("CARGO_MANIFEST_DIR"), year)
+}
+
+/// Expected answers of a year, by day, input set and part.
+///
+/// The manifest is a JSON file of the form
+/// `{ "<day>": { "<input set>": { "part_1": <answer>, "part_2": <answer> } } }`,
+/// where an answer is either a number or a string.
+pub struct Manifest {
+    answers: HashMap<(u8, String, Part), Answer>,
+}
+
+impl Manifest {
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        Manifest::try_from(content.as_str()).map_err(|e| e.in_file(path))
+    }
+
+    pub fn expected(&self, day: u8, input_set: &str, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, input_set.to_string(), part))
+    }
+
+    /// Whether the day has any expected answer, a day without any being still solved, e.g. just generated with `new`
+    #[cfg(test)]
+    pub fn has_answers(&self, day: u8) -> bool {
+        self.answers
+            .keys()
+            .any(|(answer_day, _, _)| *answer_day == day)
+    }
+}
+
+impl TryFrom<&str> for Manifest {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let root = parse_json(value, "manifest")?;
+        let days = root.as_object().ok_or(Error::parse(
+            "",
+            "Invalid manifest, expected an object of days",
+        ))?;
+
+        let mut answers = HashMap::new();
+        for (raw_day, input_sets) in days {
+            let day = parse_value::<u8>(raw_day, "a day number in manifest")?;
+            let input_sets = input_sets.as_object().ok_or(Error::parse(
+                "",
+                format!(
+                    "Invalid manifest entry for day {}, expected an object of input sets",
+                    day
+                ),
+            ))?;
+            for (input_set, parts) in input_sets {
+                let parts = parts.as_object().ok_or(Error::parse(
+                    "",
+                    format!(
+                        "Invalid manifest entry for day {} and input set {}, expected an object of parts",
+                        day, input_set
+                    ),
+                ))?;
+                for (raw_part, raw_answer) in parts {
+                    let part = Part::try_from(raw_part.as_str()).map_err(|_| {
+                        Error::parse(raw_part, "Unknown part, expected `part_1` or `part_2`")
+                    })?;
+                    let answer = match raw_answer {
+                        Value::Number(n) => Answer::Number(n.as_i64().ok_or(Error::parse(
+                            &n.to_string(),
+                            format!(
+                                "Invalid answer for day {} and input set {}, expected an integer",
+                                day, input_set
+                            ),
+                        ))?),
+                        Value::String(s) => Answer::Text(s.to_string()),
+                        other => {
+                            return Err(Error::parse(
+                                &other.to_string(),
+                                format!(
+                                    "Invalid answer for day {} and input set {}, expected a number or a string",
+                                    day, input_set
+                                ),
+                            ))
+                        }
+                    };
+                    answers.insert((day, input_set.to_string(), part), answer);
+                }
+            }
+        }
+
+        Ok(Manifest { answers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_is_parsed_into_numbers_and_texts() {
+        let manifest =
+            Manifest::try_from(r#"{ "5": { "example": { "part_1": "CMZ", "part_2": 12 } } }"#)
+                .unwrap();
+        assert_eq!(
+            manifest.expected(5, "example", Part::Part1),
+            Some(&Answer::Text("CMZ".to_string()))
+        );
+        assert_eq!(
+            manifest.expected(5, "example", Part::Part2),
+            Some(&Answer::Number(12))
+        );
+        assert_eq!(manifest.expected(5, "real", Part::Part1), None);
+        assert!(manifest.has_answers(5));
+        assert!(!manifest.has_answers(6));
+    }
+
+    #[test]
+    fn unknown_part_is_rejected() {
+        assert!(Manifest::try_from(r#"{ "1": { "real": { "part_3": 1 } } }"#).is_err());
+    }
+
+    #[test]
+    fn invalid_json_is_located_in_the_manifest() {
+        match Manifest::try_from("{\n  \"1\": {,\n}") {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e.line, Some(2));
+                assert_eq!(e.column, Some(9));
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    /// A day without any answer is still being solved, e.g. just generated with `new`, the answers of the other days
+    /// must be complete
+    #[test]
+    fn checked_in_manifest_covers_every_registered_day() {
+        for year in crate::YEARS {
+            let manifest = Manifest::load(&default_path(year.year)).unwrap();
+            for solver in year.solvers {
+                if !manifest.has_answers(solver.day()) {
+                    continue;
+                }
+                for input_set in solver.input_sets() {
+                    for part in [Part::Part1, Part::Part2] {
+                        assert!(
+                            manifest
+                                .expected(solver.day(), input_set.name, part)
+                                .is_some(),
+                            "{} day {} {} {}",
+                            year.year,
+                            solver.day(),
+                            input_set,
+                            part
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
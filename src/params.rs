@@ -0,0 +1,180 @@
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive};
+
+use crate::{Error, Part, Solver};
+
+/// Puzzle constant of a day which can be overridden with `--param <name>=<value>`
+pub struct Param {
+    pub name: &'static str,
+    /// What the parameter changes, with its default values
+    pub description: &'static str,
+    /// Parts of the day using the parameter
+    pub parts: &'static [Part],
+    /// Accepted values
+    pub range: RangeInclusive<i64>,
+}
+
+/// Values of the parameters given on the command line, checked against the parameters declared by the solver
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, i64>,
+}
+
+impl Params {
+    /// Parse the `<name>=<value>` pairs given for the parts to run, every name must be declared by the solver for
+    /// one of the parts
+    pub fn parse(
+        solver: &dyn Solver,
+        parts: &[Part],
+        raw_params: &[String],
+    ) -> Result<Self, Error> {
+        let mut values = BTreeMap::new();
+        for raw_param in raw_params {
+            let (name, raw_value) = raw_param.split_once('=').ok_or(Error::usage(format!(
+                "Invalid parameter, expected `<name>=<value>`, got {}",
+                raw_param
+            )))?;
+            let param = solver
+                .params()
+                .iter()
+                .find(|p| p.name == name)
+                .ok_or(Error::usage(format!(
+                    "Unknown parameter `{}` for {}, {}",
+                    name,
+                    solver.name(),
+                    supported_params(solver)
+                )))?;
+            if !param.parts.iter().any(|p| parts.contains(p)) {
+                return Err(Error::usage(format!(
+                    "Parameter `{}` of {} does not apply to {}",
+                    name,
+                    solver.name(),
+                    parts
+                        .iter()
+                        .map(|p| p.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" nor ")
+                )));
+            }
+            let value = raw_value
+                .replace('_', "")
+                .parse::<i64>()
+                .ok()
+                .filter(|v| param.range.contains(v))
+                .ok_or(Error::usage(format!(
+                    "Invalid value for parameter `{}` of {}, expected a number from {} to {}, got {}",
+                    name,
+                    solver.name(),
+                    param.range.start(),
+                    param.range.end(),
+                    raw_value
+                )))?;
+            values.insert(param.name, value);
+        }
+        Ok(Params { values })
+    }
+
+    /// Value of a parameter, or the given default when it has not been overridden
+    pub fn get<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T, Error> {
+        match self.values.get(name) {
+            None => Ok(default),
+            Some(value) => T::try_from(*value).map_err(|_| {
+                Error::usage(format!(
+                    "Value {} of parameter `{}` is out of range",
+                    value, name
+                ))
+            }),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<String>>();
+        write!(f, "{}", pairs.join(","))
+    }
+}
+
+fn supported_params(solver: &dyn Solver) -> String {
+    if solver.params().is_empty() {
+        return "it has no parameters".to_string();
+    }
+    let names = solver
+        .params()
+        .iter()
+        .map(|p| format!("`{}`", p.name))
+        .collect::<Vec<String>>();
+    format!("supported parameters: {}", names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Year;
+
+    fn parse(name: &str, part: Part, raw_params: &[&str]) -> Result<Params, Error> {
+        let raw_params = raw_params
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>();
+        Params::parse(
+            Year::get(2022).unwrap().find_solver(name).unwrap(),
+            &[part],
+            &raw_params,
+        )
+    }
+
+    #[test]
+    fn declared_params_are_parsed() {
+        let params = parse("rope-bridge", Part::Part2, &["knots=3"]).unwrap();
+        assert_eq!(params.get("knots", 10_usize).unwrap(), 3);
+        assert_eq!(params.to_string(), "knots=3");
+
+        let params = parse("beacon-exclusion-zone", Part::Part1, &["row=2_000_000"]).unwrap();
+        assert_eq!(params.get("row", 10_isize).unwrap(), 2_000_000);
+    }
+
+    #[test]
+    fn missing_params_give_the_default() {
+        let params = parse("rope-bridge", Part::Part1, &[]).unwrap();
+        assert!(params.is_empty());
+        assert_eq!(params.get("knots", 2_usize).unwrap(), 2);
+    }
+
+    #[test]
+    fn unknown_params_are_rejected_with_the_supported_ones() {
+        let error = parse("proboscidea-volcanium", Part::Part1, &["knots=3"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown parameter `knots` for proboscidea-volcanium, supported parameters: `minutes`, `actors`"
+        );
+        let error = parse("callories", Part::Part1, &["knots=3"]).unwrap_err();
+        assert!(error.to_string().ends_with("it has no parameters"));
+    }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        assert!(parse("rope-bridge", Part::Part1, &["knots"]).is_err());
+        assert!(parse("rope-bridge", Part::Part1, &["knots=1"]).is_err());
+        assert!(parse("rope-bridge", Part::Part1, &["knots=many"]).is_err());
+        assert!(parse("beacon-exclusion-zone", Part::Part2, &["row=10"]).is_err());
+    }
+
+    #[test]
+    fn params_of_either_part_are_accepted_when_running_both() {
+        let solver = Year::get(2022)
+            .unwrap()
+            .find_solver("beacon-exclusion-zone")
+            .unwrap();
+        let raw_params = ["row=10".to_string(), "bound=20".to_string()];
+        let params = Params::parse(solver, &[Part::Part1, Part::Part2], &raw_params).unwrap();
+        assert_eq!(params.to_string(), "bound=20,row=10");
+    }
+}
@@ -0,0 +1,529 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    cache::{CacheKey, ResultCache},
+    log, warn, Answer, CancelToken, Input, InputSet, Params, Part, Solver,
+};
+
+/// Outcome of running one part of an exercise
+pub struct RunReport {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub part: Part,
+    pub input_path: String,
+    pub result: Result<Answer, String>,
+    pub elapsed: Duration,
+    /// Messages logged by the run when they were captured, e.g. when it ran in parallel with other runs
+    pub log: Vec<String>,
+    /// Whether the answer was taken from the cache instead of being solved
+    pub cached: bool,
+}
+
+impl RunReport {
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Single JSON object describing the run, the answer is either a number or a string
+    pub fn to_json(&self) -> Value {
+        let answer = match &self.result {
+            Ok(Answer::Number(n)) => json!(n),
+            Ok(Answer::Text(s)) => json!(s),
+            Err(_) => Value::Null,
+        };
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "name": self.name,
+            "part": self.part.to_string(),
+            "input": self.input_path,
+            "answer": answer,
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1_000.0,
+            "error": self.result.as_ref().err(),
+            "cached": self.cached,
+        })
+    }
+}
+
+pub fn run_part(
+    solver: &dyn Solver,
+    part: Part,
+    input_set: InputSet,
+    cancel: CancelToken,
+) -> RunReport {
+    let filename = input_set.path(solver.year(), solver.day());
+    run_part_with_input(
+        solver,
+        part,
+        &filename,
+        input_set,
+        &Params::default(),
+        cancel,
+    )
+}
+
+/// Run a part on the input read from the given path, `-` for the standard input, with the given parameters.
+///
+/// The run stops with an error once the token is cancelled or timed out.
+pub fn run_part_with_input(
+    solver: &dyn Solver,
+    part: Part,
+    filename: &str,
+    input_set: InputSet,
+    params: &Params,
+    cancel: CancelToken,
+) -> RunReport {
+    let start = Instant::now();
+    let result = Input::read(filename, input_set)
+        .and_then(|input| {
+            let input = input.with_params(params.clone()).with_cancel(cancel);
+            solver.solve(&part, &input)
+        })
+        .map_err(|e| e.to_string());
+    let elapsed = start.elapsed();
+
+    RunReport {
+        year: solver.year(),
+        day: solver.day(),
+        name: solver.name(),
+        part,
+        input_path: filename.to_string(),
+        result,
+        elapsed,
+        log: vec![],
+        cached: false,
+    }
+}
+
+/// Run a part on an input already read, e.g. the body of a request of the HTTP API, with its parameters and its
+/// cancellation
+pub fn run_part_on_input(solver: &dyn Solver, part: Part, input: Input) -> RunReport {
+    let start = Instant::now();
+    let result = solver.solve(&part, &input).map_err(|e| e.to_string());
+    let elapsed = start.elapsed();
+
+    RunReport {
+        year: solver.year(),
+        day: solver.day(),
+        name: solver.name(),
+        part,
+        input_path: input.path,
+        result,
+        elapsed,
+        log: vec![],
+        cached: false,
+    }
+}
+
+/// Run a part on the input of the input set as `run_part`, the answer being taken from the cache when it holds one for
+/// the same input, and stored into it otherwise
+pub fn run_part_cached(
+    solver: &dyn Solver,
+    part: Part,
+    input_set: InputSet,
+    cancel: CancelToken,
+    cache: &ResultCache,
+) -> RunReport {
+    let filename = input_set.path(solver.year(), solver.day());
+    let start = Instant::now();
+    let mut cached = false;
+    let result = Input::read(&filename, input_set)
+        .and_then(|input| {
+            let key = CacheKey::new(solver, part, &input);
+            if let Some(answer) = cache.get(&key) {
+                cached = true;
+                return Ok(answer);
+            }
+            let answer = solver.solve(&part, &input.with_cancel(cancel))?;
+            if let Err(e) = cache.put(&key, &answer) {
+                warn!("Unable to cache the answer, {}", e);
+            }
+            Ok(answer)
+        })
+        .map_err(|e| e.to_string());
+    let elapsed = start.elapsed();
+
+    RunReport {
+        year: solver.year(),
+        day: solver.day(),
+        name: solver.name(),
+        part,
+        input_path: filename,
+        result,
+        elapsed,
+        log: vec![],
+        cached,
+    }
+}
+
+/// Run both parts on the input read from the given path, the input being read and parsed only once.
+///
+/// The time spent reading and parsing the input is counted in the time of the first part.
+pub fn run_both_with_input(
+    solver: &dyn Solver,
+    filename: &str,
+    input_set: InputSet,
+    params: &Params,
+    cancel: CancelToken,
+) -> Vec<RunReport> {
+    let mut start = Instant::now();
+    let parsed = Input::read(filename, input_set).and_then(|input| {
+        let input = input.with_params(params.clone()).with_cancel(cancel);
+        let puzzle = solver.parse(&input).map_err(|e| solver.locate(e, &input))?;
+        Ok((input, puzzle))
+    });
+
+    let mut reports = vec![];
+    for part in [Part::Part1, Part::Part2] {
+        let result = match &parsed {
+            Ok((input, puzzle)) => puzzle
+                .solve(&part, input)
+                .map_err(|e| solver.locate(e, input).to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        reports.push(RunReport {
+            year: solver.year(),
+            day: solver.day(),
+            name: solver.name(),
+            part,
+            input_path: filename.to_string(),
+            result,
+            elapsed: start.elapsed(),
+            log: vec![],
+            cached: false,
+        });
+        start = Instant::now();
+    }
+    reports
+}
+
+/// Run both parts of every given solver on the named input set, each part being run on one of `jobs` threads and
+/// stopped after `timeout` if any, the answers being taken from the cache if any.
+///
+/// The log of each run is captured in its report when the runs are parallel, and written as it comes otherwise. The
+/// reports are in the order of the solvers and of the parts whatever the order the runs end in. The solvers which do not declare the input set are skipped.
+pub fn run_all(
+    solvers: &[&dyn Solver],
+    input_set: &str,
+    jobs: usize,
+    timeout: Option<Duration>,
+    cache: Option<&ResultCache>,
+) -> Vec<RunReport> {
+    let mut runs = vec![];
+    for solver in solvers {
+        let Some(input_set) = solver.input_sets().iter().find(|set| set.name == input_set) else {
+            continue;
+        };
+        for part in [Part::Part1, Part::Part2] {
+            runs.push((*solver, part, *input_set));
+        }
+    }
+    parallel_map(&runs, jobs, |(solver, part, input_set)| {
+        let (report, log) = log::captured_if(jobs > 1, || {
+            let cancel = CancelToken::new(timeout);
+            match cache {
+                Some(cache) => run_part_cached(*solver, *part, *input_set, cancel, cache),
+                None => run_part(*solver, *part, *input_set, cancel),
+            }
+        });
+        RunReport { log, ..report }
+    })
+}
+
+/// Apply `f` to every item on up to `jobs` threads, each thread taking the next item once it is done with the previous
+/// one.
+///
+/// The results are in the order of the items.
+pub fn parallel_map<I: Sync, T: Send>(
+    items: &[I],
+    jobs: usize,
+    f: impl Fn(&I) -> T + Sync,
+) -> Vec<T> {
+    let next = AtomicUsize::new(0);
+    let mut results = thread::scope(|scope| {
+        let workers = (0..jobs.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = vec![];
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            return results;
+                        };
+                        results.push((index, f(item)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<(usize, T)>>()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{:.3}s", duration.as_secs_f64())
+    } else {
+        format!("{:.3}ms", duration.as_secs_f64() * 1_000.0)
+    }
+}
+
+/// Render the reports as a table, multi-line answers are continued on the next rows of the answer column and the time of
+/// the cached answers is marked as such
+pub fn format_table(reports: &[RunReport]) -> String {
+    let headers = ["Day", "Name", "Part", "Answer", "Time"];
+
+    let mut rows: Vec<[String; 5]> = vec![];
+    for report in reports {
+        let answer = match &report.result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("FAILED: {}", e),
+        };
+        let mut answer_lines = answer.lines();
+        rows.push([
+            report.day.to_string(),
+            report.name.to_string(),
+            report.part.to_string(),
+            answer_lines.next().unwrap_or("").to_string(),
+            if report.cached {
+                format!("{} (cached)", format_duration(report.elapsed))
+            } else {
+                format_duration(report.elapsed)
+            },
+        ]);
+        for line in answer_lines {
+            rows.push([
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+                line.to_string(),
+                "".to_string(),
+            ]);
+        }
+    }
+
+    let mut widths = headers.map(|h| h.len());
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = std::cmp::max(widths[i], cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: [&str; 5]| -> String {
+        let filled_cells = cells
+            .iter()
+            .rposition(|c| !c.is_empty())
+            .map_or(0, |i| i + 1);
+        cells[..filled_cells]
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| format!("{:<width$}", cell, width = widths[i]))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(headers)];
+    lines.push(
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<String>>()
+            .join("-+-"),
+    );
+    for row in &rows {
+        lines.push(format_row(row.each_ref().map(|s| s.as_str())));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Year;
+
+    #[test]
+    fn run_part_reports_the_answer() {
+        let report = run_part(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            Part::Part1,
+            InputSet::EXAMPLE,
+            CancelToken::default(),
+        );
+        assert_eq!(report.day, 1);
+        assert_eq!(report.result, Ok(Answer::Number(24000)));
+    }
+
+    #[test]
+    fn both_parts_are_reported_from_one_parsing() {
+        let reports = run_both_with_input(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            "inputs/2022/input-01-example.txt",
+            InputSet::EXAMPLE,
+            &Params::default(),
+            CancelToken::default(),
+        );
+        let results = reports
+            .iter()
+            .map(|r| (r.part, r.result.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                (Part::Part1, Ok(Answer::Number(24000))),
+                (Part::Part2, Ok(Answer::Number(45000)))
+            ]
+        );
+
+        let reports = run_both_with_input(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            "inputs/missing.txt",
+            InputSet::REAL,
+            &Params::default(),
+            CancelToken::default(),
+        );
+        assert!(reports.iter().all(|r| !r.is_success()));
+    }
+
+    #[test]
+    fn table_continues_multi_line_answers_on_next_rows() {
+        let reports = vec![
+            RunReport {
+                year: 2022,
+                day: 10,
+                name: "cathod-ray-tube",
+                part: Part::Part2,
+                input_path: "inputs/2022/input-10.txt".to_string(),
+                result: Ok(Answer::Text("##..\n..##\n".to_string())),
+                elapsed: Duration::from_millis(2),
+                log: vec![],
+                cached: false,
+            },
+            RunReport {
+                year: 2022,
+                day: 11,
+                name: "monkey-in-the-middle",
+                part: Part::Part1,
+                input_path: "inputs/2022/input-11.txt".to_string(),
+                result: Ok(Answer::Number(10605)),
+                elapsed: Duration::from_millis(1),
+                log: vec![],
+                cached: true,
+            },
+        ];
+        assert_eq!(
+            format_table(&reports),
+            "Day | Name                 | Part   | Answer | Time
+----+----------------------+--------+--------+-----------------
+10  | cathod-ray-tube      | part_2 | ##..   | 2.000ms
+    |                      |        | ..##
+11  | monkey-in-the-middle | part_1 | 10605  | 1.000ms (cached)"
+        );
+    }
+
+    #[test]
+    fn reports_are_serialized_with_the_answer_or_the_error() {
+        let report = run_part(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            Part::Part1,
+            InputSet::EXAMPLE,
+            CancelToken::default(),
+        );
+        let json = report.to_json();
+        assert_eq!(json["year"], 2022);
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["part"], "part_1");
+        assert_eq!(json["input"], InputSet::EXAMPLE.path(2022, 1));
+        assert_eq!(json["answer"], 24000);
+        assert!(json["error"].is_null());
+
+        let report = run_part_with_input(
+            Year::get(2022).unwrap().find_solver("callories").unwrap(),
+            Part::Part1,
+            "inputs/missing.txt",
+            InputSet::REAL,
+            &Params::default(),
+            CancelToken::default(),
+        );
+        let json = report.to_json();
+        assert!(json["answer"].is_null());
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .contains("inputs/missing.txt"));
+    }
+
+    #[test]
+    fn parallel_results_keep_the_order_of_the_items() {
+        // The first items take the longest, so that they end last
+        let delays = [40, 30, 20, 10, 0];
+        let results = parallel_map(&delays, 3, |delay| {
+            thread::sleep(Duration::from_millis(*delay));
+            delay * 2
+        });
+        assert_eq!(results, vec![80, 60, 40, 20, 0]);
+        assert_eq!(parallel_map(&delays, 0, |delay| *delay), delays);
+        assert!(parallel_map(&[] as &[u8], 4, |item| *item).is_empty());
+    }
+
+    #[test]
+    fn runs_in_parallel_are_reported_in_order() {
+        let year = Year::get(2022).unwrap();
+        let reports = run_all(&year.solvers[..4], "example", 4, None, None);
+        let runs = reports
+            .iter()
+            .map(|r| (r.day, r.part))
+            .collect::<Vec<(u8, Part)>>();
+        assert_eq!(
+            runs,
+            (1..=4)
+                .flat_map(|day| [(day, Part::Part1), (day, Part::Part2)])
+                .collect::<Vec<(u8, Part)>>()
+        );
+        assert!(reports.iter().all(|r| r.is_success()));
+        assert_eq!(reports[0].result, Ok(Answer::Number(24000)));
+    }
+
+    #[test]
+    fn durations_are_formatted_in_milliseconds_under_a_second() {
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.500ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.250s");
+    }
+
+    #[test]
+    fn cached_answers_are_not_solved_again() {
+        let dir = std::env::temp_dir().join(format!("advent-of-code-runs-{}", std::process::id()));
+        let cache = ResultCache::new(&dir);
+        let solver = Year::get(2022).unwrap().find_solver("callories").unwrap();
+        let run = || {
+            run_part_cached(
+                solver,
+                Part::Part2,
+                InputSet::EXAMPLE,
+                CancelToken::default(),
+                &cache,
+            )
+        };
+        let report = run();
+        assert!(!report.cached);
+        assert_eq!(report.result, Ok(Answer::Number(45000)));
+        let report = run();
+        assert!(report.cached);
+        assert_eq!(report.result, Ok(Answer::Number(45000)));
+        assert_eq!(report.to_json()["cached"], true);
+        cache.clear().unwrap();
+    }
+}
@@ -0,0 +1,645 @@
+use std::{fs, path::Path};
+
+use crate::{Error, YEARS};
+
+/// Skeleton of a new exercise module, `__YEAR__`, `__DAY__`, `__NAME__`, `__MODULE__`, `__TITLE__` and `__STRUCT__` are replaced when generating it
+const MODULE_TEMPLATE: &str = r#"use crate::{debug, input_dir, Answer, Error, Input, Puzzle, Solver};
+
+pub struct __STRUCT__;
+
+impl Solver for __STRUCT__ {
+    fn name(&self) -> &'static str {
+        "__NAME__"
+    }
+
+    fn year(&self) -> u16 {
+        __YEAR__
+    }
+
+    fn day(&self) -> u8 {
+        __DAY__
+    }
+
+    fn module(&self) -> &'static str {
+        "__MODULE__"
+    }
+
+    fn title(&self) -> &'static str {
+        "__TITLE__"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_notes(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one entry per line"
+    }
+}
+
+/// Parsed input, shared by both parts
+pub struct Notes {
+    lines: Vec<String>,
+}
+
+impl Puzzle for Notes {
+    fn part_1(&self, _input: &Input) -> Result<Answer, Error> {
+        Ok(self.solve_part_1()?.into())
+    }
+
+    fn part_2(&self, _input: &Input) -> Result<Answer, Error> {
+        Ok(self.solve_part_2()?.into())
+    }
+}
+
+pub fn parse_notes(content: &str) -> Result<Notes, Error> {
+    Ok(Notes {
+        lines: content.lines().map(String::from).collect(),
+    })
+}
+
+impl Notes {
+    fn solve_part_1(&self) -> Result<usize, Error> {
+        debug!("Solving part 1 on {} lines", self.lines.len());
+        Err(Error::no_solution("Part 1 is not implemented yet"))
+    }
+
+    fn solve_part_2(&self) -> Result<usize, Error> {
+        debug!("Solving part 2 on {} lines", self.lines.len());
+        Err(Error::no_solution("Part 2 is not implemented yet"))
+    }
+}
+
+pub fn solve_part_1(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    parse_notes(&content)?.solve_part_1()
+}
+
+pub fn solve_part_2(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    parse_notes(&content)?.solve_part_2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "expected answer not known yet"]
+    fn example_part_1_has_right_answer() {
+        assert_eq!(solve_part_1("inputs/__YEAR__/input-__PADDED_DAY__-example.txt").unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "expected answer not known yet"]
+    fn part_1_has_right_answer() {
+        if input_dir::skip_without_key("inputs/__YEAR__/input-__PADDED_DAY__.txt") {
+            return;
+        }
+        assert_eq!(solve_part_1("inputs/__YEAR__/input-__PADDED_DAY__.txt").unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "expected answer not known yet"]
+    fn example_part_2_has_right_answer() {
+        assert_eq!(solve_part_2("inputs/__YEAR__/input-__PADDED_DAY__-example.txt").unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "expected answer not known yet"]
+    fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/__YEAR__/input-__PADDED_DAY__.txt") {
+            return;
+        }
+        assert_eq!(solve_part_2("inputs/__YEAR__/input-__PADDED_DAY__.txt").unwrap(), 0);
+    }
+}
+"#;
+
+/// Skeleton of the module of a new year, `__YEAR__` is replaced when generating it
+const YEAR_TEMPLATE: &str = r#"use crate::Solver;
+
+/// Registered solvers of __YEAR__, in the ascending order of the days
+pub static REGISTRY: &[&dyn Solver] = &[
+];
+"#;
+
+/// Names of a new exercise, derived from the name given on the command line
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayNames {
+    /// Name used on the command line, e.g. `not-enough-minerals`
+    pub name: String,
+    /// Name of the module, e.g. `not_enough_minerals`
+    pub module: String,
+    /// Name of the solver struct, e.g. `NotEnoughMinerals`
+    pub struct_name: String,
+    /// Title of the puzzle, e.g. `Not Enough Minerals`
+    pub title: String,
+}
+
+impl TryFrom<&str> for DayNames {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let words = value
+            .split(['-', '_'])
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<&str>>();
+        let is_valid = value.starts_with(|c: char| c.is_ascii_lowercase())
+            && value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !is_valid || words.is_empty() {
+            return Err(Error::usage(format!(
+                "Invalid exercise name, expected lowercase words separated by `-`, e.g. `not-enough-minerals`, got {}",
+                value
+            )));
+        }
+
+        let capitalized_words = words
+            .iter()
+            .map(|w| {
+                let mut chars = w.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect::<Vec<String>>();
+        Ok(DayNames {
+            name: words.join("-"),
+            module: words.join("_"),
+            struct_name: capitalized_words.concat(),
+            title: capitalized_words.join(" "),
+        })
+    }
+}
+
+/// Generate the module of a new day of a year under `root`, create its empty example input and register it.
+///
+/// The real input is not created, it is fetched then committed encrypted with `encrypt-inputs`.
+///
+/// The first day of a year also creates the module tree, the answer manifest and the input folder of the year.
+/// Returns the created or modified files. Nothing is written if the day or the module already exists.
+pub fn scaffold(root: &Path, year: u16, day: u8, names: &DayNames) -> Result<Vec<String>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::usage(format!(
+            "Invalid day, expected a number from 1 to 25, got {}",
+            day
+        )));
+    }
+    let solvers = YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .map_or(&[][..], |y| y.solvers);
+    if let Some(solver) = solvers
+        .iter()
+        .find(|s| s.day() == day || s.matches(&names.name))
+    {
+        return Err(Error::usage(format!(
+            "Day {} of {} is already registered as `{}`, refusing to overwrite it",
+            solver.day(),
+            year,
+            solver.name()
+        )));
+    }
+
+    let year_module = format!("year{}", year);
+    let year_dir = root.join("src").join(&year_module);
+    let module_path = year_dir.join(format!("{}.rs", names.module));
+    if module_path.exists() {
+        return Err(Error::usage(format!(
+            "Module {} already exists, refusing to overwrite it",
+            module_path.display()
+        )));
+    }
+
+    // Compute every change before writing anything, so that a failure leaves the tree untouched
+    let position = solvers.iter().filter(|s| s.day() < day).count();
+    let year_path = year_dir.join("mod.rs");
+    let lib_path = root.join("src").join("lib.rs");
+    let solver_path = root.join("src").join("solver.rs");
+    let manifest_path = root.join("answers").join(format!("{}.json", year));
+    let readme_path = root.join("README.md");
+    let new_year = !year_path.exists();
+    let year_source = if new_year {
+        let empty_year = YEAR_TEMPLATE.replace("__YEAR__", &year.to_string());
+        register_solver(
+            &format!("pub mod {};\n\n{}", names.module, empty_year),
+            names,
+            0,
+        )?
+    } else {
+        let year_source = register_module(&read(&year_path)?, &names.module)?;
+        register_solver(&year_source, names, position)?
+    };
+    let registration = if new_year {
+        Some((
+            register_module(&read(&lib_path)?, &year_module)?,
+            register_year(&read(&solver_path)?, year)?,
+        ))
+    } else {
+        None
+    };
+    let readme = if readme_path.exists() {
+        Some(register_in_readme(
+            &read(&readme_path)?,
+            year,
+            day,
+            &names.name,
+        ))
+    } else {
+        None
+    };
+
+    let mut changed_files = vec![];
+    write(
+        &module_path,
+        &module_source(year, day, names),
+        &mut changed_files,
+    )?;
+    write(&year_path, &year_source, &mut changed_files)?;
+    let example_path = root
+        .join("inputs")
+        .join(year.to_string())
+        .join(format!("input-{:02}-example.txt", day));
+    if !example_path.exists() {
+        write(&example_path, "", &mut changed_files)?;
+    }
+    if let Some((lib, solver)) = registration {
+        write(&lib_path, &lib, &mut changed_files)?;
+        write(&solver_path, &solver, &mut changed_files)?;
+    }
+    if !manifest_path.exists() {
+        write(&manifest_path, "{}\n", &mut changed_files)?;
+    }
+    if let Some(readme) = readme {
+        write(&readme_path, &readme, &mut changed_files)?;
+    }
+    Ok(changed_files)
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::io(&path.display().to_string(), e))
+}
+
+fn write(path: &Path, contents: &str, changed_files: &mut Vec<String>) -> Result<(), Error> {
+    let display = path.display().to_string();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io(&display, e))?;
+    }
+    fs::write(path, contents).map_err(|e| Error::io(&display, e))?;
+    changed_files.push(display);
+    Ok(())
+}
+
+fn module_source(year: u16, day: u8, names: &DayNames) -> String {
+    MODULE_TEMPLATE
+        .replace("__YEAR__", &year.to_string())
+        .replace("__STRUCT__", &names.struct_name)
+        .replace("__NAME__", &names.name)
+        .replace("__MODULE__", &names.module)
+        .replace("__TITLE__", &names.title)
+        .replace("__PADDED_DAY__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string())
+}
+
+/// Add `pub mod <module>;` to the module declarations at the top of `lib.rs` or of the module of a year, keeping them in the
+/// alphabetical order
+fn register_module(lib: &str, module: &str) -> Result<String, Error> {
+    let declaration = format!("pub mod {};", module);
+    let mut lines = lib.lines().collect::<Vec<&str>>();
+    let declared_module = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .or(line.strip_prefix("mod "))
+            .and_then(|l| l.strip_suffix(';'))
+            .map(String::from)
+    };
+    let declarations = lines
+        .iter()
+        .take_while(|l| declared_module(l).is_some())
+        .count();
+    if declarations == 0 {
+        return Err(Error::invalid_state(
+            "Unable to find the module declarations at the top of the module",
+        ));
+    }
+    if lines[..declarations]
+        .iter()
+        .any(|l| declared_module(l).as_deref() == Some(module))
+    {
+        return Err(Error::usage(format!(
+            "Module {} is already declared, refusing to overwrite it",
+            module
+        )));
+    }
+    let position = lines[..declarations]
+        .iter()
+        .take_while(|l| declared_module(l).is_some_and(|m| m.as_str() < module))
+        .count();
+    lines.insert(position, &declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the solver of the module to the `REGISTRY` of the year at the given position, one entry per line.
+///
+/// A registry written on a single line, as rustfmt does for a year of a few days, is also understood.
+fn register_solver(year: &str, names: &DayNames, position: usize) -> Result<String, Error> {
+    let declaration = "pub static REGISTRY: &[&dyn Solver] = &[";
+    let start = year.find(declaration).ok_or(Error::invalid_state(
+        "Unable to find the REGISTRY of the year",
+    ))? + declaration.len();
+    let end = start
+        + year[start..].find("];").ok_or(Error::invalid_state(
+            "Unable to find the end of the REGISTRY of the year",
+        ))?;
+    let mut entries = year[start..end]
+        .split(',')
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+        .collect::<Vec<String>>();
+    entries.insert(
+        position.min(entries.len()),
+        format!("&{}::{}", names.module, names.struct_name),
+    );
+    let entries = entries
+        .iter()
+        .map(|e| format!("    {},\n", e))
+        .collect::<String>();
+    Ok(format!("{}\n{}{}", &year[..start], entries, &year[end..]))
+}
+
+/// Add a year to the `YEARS` of `solver.rs`, in the ascending order, formatted as rustfmt does
+fn register_year(solver: &str, year: u16) -> Result<String, Error> {
+    let declaration = "pub static YEARS: &[Year] = &[";
+    let start = solver.find(declaration).ok_or(Error::invalid_state(
+        "Unable to find the YEARS in solver.rs",
+    ))?;
+    let end = start
+        + solver[start..].find("];").ok_or(Error::invalid_state(
+            "Unable to find the end of the YEARS in solver.rs",
+        ))?;
+    let mut years = solver[start..end]
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("year: "))
+        .filter_map(|line| line.trim_end_matches(',').parse::<u16>().ok())
+        .collect::<Vec<u16>>();
+    if years.contains(&year) {
+        return Err(Error::usage(format!(
+            "Year {} is already registered in solver.rs, refusing to overwrite it",
+            year
+        )));
+    }
+    years.push(year);
+    years.sort_unstable();
+
+    let entry = |year: &u16, indent: &str| {
+        format!(
+            "{indent}Year {{\n{indent}    year: {year},\n{indent}    solvers: crate::year{year}::REGISTRY,\n{indent}}}"
+        )
+    };
+    let entries = match &years[..] {
+        [year] => entry(year, ""),
+        _ => format!(
+            "\n{},\n",
+            years
+                .iter()
+                .map(|year| entry(year, "    "))
+                .collect::<Vec<String>>()
+                .join(",\n")
+        ),
+    };
+    Ok(format!(
+        "{}{}{}{}",
+        &solver[..start],
+        declaration,
+        entries,
+        &solver[end..]
+    ))
+}
+
+/// Add the exercise to the list of exercises of the README, in the order of the days
+fn register_in_readme(readme: &str, year: u16, day: u8, name: &str) -> String {
+    let exercise_day = |line: &str| {
+        line.strip_prefix("- Exercise #")
+            .and_then(|l| l.split(':').next())
+            .and_then(|d| d.parse::<u8>().ok())
+    };
+    let mut lines = readme.lines().map(String::from).collect::<Vec<String>>();
+    let header = format!("Exercises of {}:", year);
+    let Some(year_start) = lines.iter().position(|l| *l == header) else {
+        // The first day of a year starts a new list after the list of the previous years
+        let Some(last) = lines.iter().rposition(|l| exercise_day(l).is_some()) else {
+            return readme.to_string();
+        };
+        let list = [
+            String::new(),
+            header,
+            String::new(),
+            format!("- Exercise #{}: `{}`.", day, name),
+        ];
+        lines.splice(last + 1..last + 1, list);
+        return lines.join("\n") + "\n";
+    };
+    let Some(first) = lines[year_start..]
+        .iter()
+        .position(|l| exercise_day(l).is_some())
+        .map(|i| year_start + i)
+    else {
+        return readme.to_string();
+    };
+    let count = lines[first..]
+        .iter()
+        .take_while(|l| exercise_day(l).is_some())
+        .count();
+    let position = lines[first..first + count]
+        .iter()
+        .take_while(|l| exercise_day(l).is_some_and(|d| d < day))
+        .count();
+    lines.insert(first + position, format!("- Exercise #{}: `{}`", day, name));
+    for (i, line) in lines[first..first + count + 1].iter_mut().enumerate() {
+        let trimmed = line.trim_end_matches([',', '.']).to_string();
+        *line = trimmed + if i == count { "." } else { "," };
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(value: &str) -> DayNames {
+        DayNames::try_from(value).unwrap()
+    }
+
+    /// Registry of `solver.rs` with a single year, as rustfmt formats it
+    const YEARS_FIXTURE: &str = "pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    solvers: crate::year2022::REGISTRY,
+}];";
+
+    #[test]
+    fn names_are_derived_from_the_given_name() {
+        assert_eq!(
+            names("not-enough-minerals"),
+            DayNames {
+                name: "not-enough-minerals".to_string(),
+                module: "not_enough_minerals".to_string(),
+                struct_name: "NotEnoughMinerals".to_string(),
+                title: "Not Enough Minerals".to_string(),
+            }
+        );
+        assert_eq!(names("grove_positioning").name, "grove-positioning");
+        assert!(DayNames::try_from("Not Enough").is_err());
+        assert!(DayNames::try_from("19-minerals").is_err());
+    }
+
+    #[test]
+    fn module_is_declared_in_alphabetical_order() {
+        let lib =
+            "pub mod assignment;\nmod bench;\npub mod pyroclastic_flow;\n\npub use error::Error;\n";
+        assert_eq!(
+            register_module(lib, "not_enough_minerals").unwrap(),
+            "pub mod assignment;\nmod bench;\npub mod not_enough_minerals;\npub mod pyroclastic_flow;\n\npub use error::Error;\n"
+        );
+        assert!(register_module(lib, "assignment").is_err());
+    }
+
+    #[test]
+    fn solver_is_registered_at_its_day() {
+        let year = "pub mod callories;
+pub mod rucksacks;
+
+use crate::Solver;
+
+pub static REGISTRY: &[&dyn Solver] = &[
+    &callories::Callories,
+    &rucksacks::Rucksack,
+];
+";
+        assert_eq!(
+            register_solver(year, &names("rock-paper-scissors"), 1).unwrap(),
+            "pub mod callories;
+pub mod rucksacks;
+
+use crate::Solver;
+
+pub static REGISTRY: &[&dyn Solver] = &[
+    &callories::Callories,
+    &rock_paper_scissors::RockPaperScissors,
+    &rucksacks::Rucksack,
+];
+"
+        );
+        let single_line = "pub static REGISTRY: &[&dyn Solver] = &[&callories::Callories];\n";
+        assert_eq!(
+            register_solver(single_line, &names("rucksack"), 1).unwrap(),
+            "pub static REGISTRY: &[&dyn Solver] = &[\n    &callories::Callories,\n    &rucksack::Rucksack,\n];\n"
+        );
+    }
+
+    #[test]
+    fn years_are_registered_as_rustfmt_formats_them() {
+        assert!(register_year(YEARS_FIXTURE, 2022).is_err());
+        let with_2015 = register_year(YEARS_FIXTURE, 2015).unwrap();
+        assert_eq!(
+            with_2015,
+            "pub static YEARS: &[Year] = &[
+    Year {
+        year: 2015,
+        solvers: crate::year2015::REGISTRY,
+    },
+    Year {
+        year: 2022,
+        solvers: crate::year2022::REGISTRY,
+    },
+];"
+        );
+        assert!(register_year(&with_2015, 2023)
+            .unwrap()
+            .ends_with("solvers: crate::year2023::REGISTRY,\n    },\n];"));
+    }
+
+    #[test]
+    fn exercise_is_listed_in_the_readme() {
+        let readme = "Intro\n\nExercises of 2022:\n\n- Exercise #1: `callories`,\n- Exercise #2: `rock-paper-scissors`.\n\nOutro\n";
+        assert_eq!(
+            register_in_readme(readme, 2022, 3, "rucksack"),
+            "Intro\n\nExercises of 2022:\n\n- Exercise #1: `callories`,\n- Exercise #2: `rock-paper-scissors`,\n- Exercise #3: `rucksack`.\n\nOutro\n"
+        );
+        assert_eq!(
+            register_in_readme(readme, 2023, 1, "trebuchet"),
+            "Intro\n\nExercises of 2022:\n\n- Exercise #1: `callories`,\n- Exercise #2: `rock-paper-scissors`.\n\nExercises of 2023:\n\n- Exercise #1: `trebuchet`.\n\nOutro\n"
+        );
+    }
+
+    #[test]
+    fn first_day_of_a_year_creates_the_year() {
+        let root =
+            std::env::temp_dir().join(format!("advent-of-code-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "mod bench;\npub mod year2022;\n\npub use error::Error;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/solver.rs"),
+            format!("use crate::Solver;\n\n{}\n", YEARS_FIXTURE),
+        )
+        .unwrap();
+        fs::write(
+            root.join("README.md"),
+            "Exercises of 2022:\n\n- Exercise #1: `callories`.\n",
+        )
+        .unwrap();
+        // A year without solutions whatever the years already registered
+        let new_year = YEARS[YEARS.len() - 1].year + 1;
+
+        let changed_files = scaffold(&root, new_year, 1, &names("trebuchet")).unwrap();
+        assert_eq!(changed_files.len(), 7);
+        let year_dir = root.join(format!("src/year{}", new_year));
+        let year = fs::read_to_string(year_dir.join("mod.rs")).unwrap();
+        assert!(year.starts_with("pub mod trebuchet;\n"));
+        assert!(year.contains("= &[\n    &trebuchet::Trebuchet,\n];"));
+        let module = fs::read_to_string(year_dir.join("trebuchet.rs")).unwrap();
+        assert!(module.contains(&format!(
+            "fn year(&self) -> u16 {{\n        {}\n    }}",
+            new_year
+        )));
+        assert!(module.contains(&format!("inputs/{}/input-01.txt", new_year)));
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains(&format!("pub mod year2022;\npub mod year{};\n", new_year)));
+        assert!(fs::read_to_string(root.join("src/solver.rs"))
+            .unwrap()
+            .contains(&format!("solvers: crate::year{}::REGISTRY", new_year)));
+        assert!(fs::read_to_string(root.join("README.md"))
+            .unwrap()
+            .contains(&format!(
+                "Exercises of {}:\n\n- Exercise #1: `trebuchet`.",
+                new_year
+            )));
+        assert_eq!(
+            fs::read_to_string(root.join(format!("answers/{}.json", new_year))).unwrap(),
+            "{}\n"
+        );
+        let inputs_dir = root.join(format!("inputs/{}", new_year));
+        assert!(inputs_dir.join("input-01-example.txt").exists());
+        assert!(!inputs_dir.join("input-01.txt").exists());
+
+        let changed_files = scaffold(&root, new_year, 2, &names("cube-conundrum")).unwrap();
+        assert_eq!(changed_files.len(), 4);
+        assert!(fs::read_to_string(year_dir.join("mod.rs"))
+            .unwrap()
+            .contains("pub mod cube_conundrum;\npub mod trebuchet;\n"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn existing_day_is_not_overwritten() {
+        let error =
+            scaffold(Path::new("/nonexistent"), 2022, 9, &names("another-rope")).unwrap_err();
+        assert!(error.to_string().contains("refusing to overwrite"));
+    }
+}
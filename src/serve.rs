@@ -0,0 +1,365 @@
+use std::{
+    io::{self, Read},
+    panic::{self, AssertUnwindSafe},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Request, Response, Server};
+
+use crate::{info, runner, warn, CancelToken, Error, Input, InputSet, Params, Part, Year};
+
+/// Time limit of a solve request when none is given with `--timeout`
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Largest input accepted in the body of a solve request, in bytes
+pub const MAX_INPUT_SIZE: usize = 10 * 1024 * 1024;
+
+/// Path given to the inputs of the requests, shown in the parse errors
+const INPUT_PATH: &str = "request";
+
+/// Answer to a request of the API, a status code and a JSON body
+#[derive(Debug)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Reply {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Reply {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// Serve the API of the solvers of the year on the port of the local host, until the process is interrupted.
+///
+/// The requests are handled by `jobs` threads, so that at most `jobs` solves run at the same time, the other requests
+/// waiting for a free thread. Each solve is stopped at the next check of its cancellation by the solver once `timeout`
+/// is elapsed, a solver which panics being answered with a 500 reply without stopping its thread.
+pub fn serve(year: &Year, port: u16, jobs: usize, timeout: Duration) -> Result<(), Error> {
+    let address = format!("127.0.0.1:{}", port);
+    let server = Server::http(&address).map_err(|e| Error::io(&address, io::Error::other(e)))?;
+    let address = server
+        .server_addr()
+        .to_ip()
+        .map_or(address, |address| address.to_string());
+    println!(
+        "Serving the solvers of {} on http://{}, press Ctrl-C to stop",
+        year.year, address
+    );
+    serve_requests(year, &server, jobs, timeout);
+    Ok(())
+}
+
+fn serve_requests(year: &Year, server: &Server, jobs: usize, timeout: Duration) {
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    respond(year, request, timeout);
+                }
+            });
+        }
+    });
+}
+
+fn respond(year: &Year, mut request: Request, timeout: Duration) {
+    let start = Instant::now();
+    let mut body = vec![];
+    // One more byte than accepted is read, so that a larger input is told apart from an input of the largest size
+    let reply = match request
+        .as_reader()
+        .take(MAX_INPUT_SIZE as u64 + 1)
+        .read_to_end(&mut body)
+    {
+        Ok(_) => panic::catch_unwind(AssertUnwindSafe(|| {
+            handle(
+                year,
+                request.method().as_str(),
+                request.url(),
+                &body,
+                timeout,
+            )
+        }))
+        .unwrap_or_else(|_| Reply::error(500, "The solver panicked while handling the request")),
+        Err(e) => Reply::error(400, format!("Unable to read the request body, {}", e)),
+    };
+    info!(
+        "{} {} {} in {}",
+        request.method(),
+        request.url(),
+        reply.status,
+        runner::format_duration(start.elapsed())
+    );
+    let response = Response::from_string(reply.body.to_string())
+        .with_status_code(reply.status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").expect("Valid header"));
+    if let Err(e) = request.respond(response) {
+        warn!("Unable to answer the request, {}", e);
+    }
+}
+
+/// Answer a request of the API:
+///
+/// - `GET /days` lists the exercises of the year,
+/// - `POST /solve/<day>/<part>` solves a part on the input given as body, the day being chosen by its number or one
+///   of its names, the query giving the parameters as `<name>=<value>` and the input set giving their defaults as
+///   `input_set=<name>`, e.g. `POST /solve/14/part_1?input_set=example`.
+pub fn handle(year: &Year, method: &str, url: &str, body: &[u8], timeout: Duration) -> Reply {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+    match (method, &segments[..]) {
+        ("GET", ["days"]) => Reply {
+            status: 200,
+            body: days(year),
+        },
+        ("POST", ["solve", day, part]) => solve(year, day, part, query, body, timeout)
+            .unwrap_or_else(|e| Reply::error(400, e.to_string())),
+        (_, ["days"] | ["solve", _, _]) => {
+            Reply::error(405, format!("Method {} is not allowed on {}", method, path))
+        }
+        _ => Reply::error(
+            404,
+            format!(
+                "Unknown endpoint {}, expected `GET /days` or `POST /solve/<day>/<part>`",
+                path
+            ),
+        ),
+    }
+}
+
+/// Exercises of the year, with their names, their input sets and their parameters
+fn days(year: &Year) -> Value {
+    let days = year
+        .solvers
+        .iter()
+        .map(|solver| {
+            let params = solver
+                .params()
+                .iter()
+                .map(|param| {
+                    json!({
+                        "name": param.name,
+                        "description": param.description,
+                        "parts": param.parts.iter().map(|p| p.to_string()).collect::<Vec<String>>(),
+                        "min": param.range.start(),
+                        "max": param.range.end(),
+                    })
+                })
+                .collect::<Vec<Value>>();
+            json!({
+                "day": solver.day(),
+                "name": solver.name(),
+                "title": solver.title(),
+                "module": solver.module(),
+                "aliases": solver.aliases(),
+                "version": solver.version(),
+                "input_format": solver.input_format(),
+                "input_sets": solver.input_sets().iter().map(|set| set.name).collect::<Vec<&str>>(),
+                "params": params,
+            })
+        })
+        .collect::<Vec<Value>>();
+    json!({ "year": year.year, "days": days })
+}
+
+/// Solve a part on the body of the request, a failed run being reported with its error
+fn solve(
+    year: &Year,
+    day: &str,
+    part: &str,
+    query: &str,
+    body: &[u8],
+    timeout: Duration,
+) -> Result<Reply, Error> {
+    let solver = year.solver_named(day)?;
+    let part = Part::try_from(part)?;
+    if body.len() > MAX_INPUT_SIZE {
+        return Ok(Reply::error(
+            413,
+            format!("Input too large, expected at most {} bytes", MAX_INPUT_SIZE),
+        ));
+    }
+    let contents = std::str::from_utf8(body)
+        .map_err(|_| Error::usage("Invalid input, expected UTF-8 text"))?;
+
+    let mut input_set = InputSet::REAL;
+    let mut raw_params = vec![];
+    // The names and the values of the query are percent-encoded, `+` standing for a space
+    for (name, value) in form_urlencoded::parse(query.as_bytes()) {
+        match name.as_ref() {
+            "input_set" => input_set = InputSet::find(solver, &value)?,
+            _ => raw_params.push(format!("{}={}", name, value)),
+        }
+    }
+    let params = Params::parse(solver, &[part], &raw_params)?;
+
+    let input = Input::new(INPUT_PATH, contents, input_set)
+        .with_params(params)
+        .with_cancel(CancelToken::new(Some(timeout)));
+    let report = runner::run_part_on_input(solver, part, input);
+    Ok(Reply {
+        status: if report.is_success() { 200 } else { 422 },
+        body: report.to_json(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn request(method: &str, url: &str, body: &str) -> Reply {
+        handle(
+            Year::get(2022).unwrap(),
+            method,
+            url,
+            body.as_bytes(),
+            DEFAULT_TIMEOUT,
+        )
+    }
+
+    #[test]
+    fn days_are_listed() {
+        let reply = request("GET", "/days", "");
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body["year"], 2022);
+        assert_eq!(reply.body["days"][0]["name"], "callories");
+        assert_eq!(reply.body["days"][8]["params"][0]["name"], "knots");
+        assert_eq!(reply.body["days"][8]["input_sets"][0], "example");
+    }
+
+    #[test]
+    fn parts_are_solved_on_the_body() {
+        let input = input_dir_example("input-01-example.txt");
+        let reply = request("POST", "/solve/1/part_2", &input);
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body["answer"], 45000);
+        assert!(reply.body["error"].is_null());
+        assert!(reply.body["elapsed_ms"].is_number());
+
+        let reply = request(
+            "POST",
+            "/solve/rope-bridge/part_2?input_set=example&knots=2",
+            "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n",
+        );
+        assert_eq!(reply.body["answer"], 13);
+
+        let reply = request(
+            "POST",
+            "/solve/rope-bridge/part_2?input%5Fset=exam%70le&knots=%32",
+            "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n",
+        );
+        assert_eq!(reply.body["answer"], 13);
+
+        let reply = request("POST", "/solve/1/part_1", "12\nabc\n");
+        assert_eq!(reply.status, 422);
+        assert!(reply.body["answer"].is_null());
+        assert!(reply.body["error"].as_str().unwrap().contains("abc"));
+    }
+
+    #[test]
+    fn solves_are_stopped_after_the_timeout() {
+        let input = input_dir_example("input-16-example.txt");
+        let reply = handle(
+            Year::get(2022).unwrap(),
+            "POST",
+            "/solve/16/part_1",
+            input.as_bytes(),
+            Duration::from_nanos(1),
+        );
+        assert_eq!(reply.status, 422);
+        assert!(reply.body["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("Timed out after"));
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        assert_eq!(request("GET", "/solve/1/part_1", "").status, 405);
+        assert_eq!(request("POST", "/days", "").status, 405);
+        assert_eq!(request("GET", "/", "").status, 404);
+        assert_eq!(request("POST", "/solve/1/part_3", "1\n").status, 400);
+        assert_eq!(request("POST", "/solve/unknown/part_1", "1\n").status, 400);
+        let reply = request("POST", "/solve/15/part_1?bound=20", "");
+        assert_eq!(reply.status, 400);
+        assert!(reply.body["error"]
+            .as_str()
+            .unwrap()
+            .contains("does not apply to part_1"));
+    }
+
+    #[test]
+    fn panicking_solves_leave_the_server_answering() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || serve_requests(&PANICKING_YEAR, &server, 1, DEFAULT_TIMEOUT));
+
+        let send = |method: &str, path: &str| {
+            let mut stream = std::net::TcpStream::connect(address).unwrap();
+            write!(
+                stream,
+                "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                method, path, address
+            )
+            .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+        for _ in 0..2 {
+            let response = send("POST", "/solve/1/part_1");
+            assert!(response.starts_with("HTTP/1.1 500"), "{}", response);
+            assert!(response.contains("panicked"));
+        }
+        assert!(send("GET", "/days").starts_with("HTTP/1.1 200"));
+    }
+
+    /// Solver failing with a panic on every input
+    struct PanickingSolver;
+
+    impl crate::Solver for PanickingSolver {
+        fn name(&self) -> &'static str {
+            "panicking"
+        }
+
+        fn year(&self) -> u16 {
+            2022
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn module(&self) -> &'static str {
+            "panicking"
+        }
+
+        fn title(&self) -> &'static str {
+            "Panicking"
+        }
+
+        fn parse(&self, _input: &Input) -> Result<Box<dyn crate::Puzzle>, Error> {
+            panic!("Panicking on purpose");
+        }
+
+        fn input_format(&self) -> &'static str {
+            "anything"
+        }
+    }
+
+    static PANICKING_YEAR: Year = Year {
+        year: 2022,
+        solvers: &[&PanickingSolver],
+    };
+
+    fn input_dir_example(file_name: &str) -> String {
+        crate::input_dir::read(&format!("inputs/2022/{}", file_name)).unwrap()
+    }
+}
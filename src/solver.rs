@@ -0,0 +1,500 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use crate::{input_dir, CancelToken, Error, InputSet, Param, Params, Part};
+
+/// A solver for one day of the Advent of code.
+///
+/// Each exercise module exposes a unit struct implementing this trait, the struct is then added to the `REGISTRY` of its year.
+/// The solver parses the input into a `Puzzle`, which is shared by both parts.
+pub trait Solver: Sync {
+    /// Name used to select the exercise on the command line, e.g. `rope-bridge`
+    fn name(&self) -> &'static str;
+
+    /// Year of the Advent of code of the exercise
+    fn year(&self) -> u16;
+
+    /// Day of the exercise in the Advent of code calendar
+    fn day(&self) -> u8;
+
+    /// Name of the module of the exercise, e.g. `rope_bridge`, also accepted on the command line
+    fn module(&self) -> &'static str;
+
+    /// Title of the puzzle on the Advent of code website, e.g. `Rope Bridge`, also accepted on the command line
+    fn title(&self) -> &'static str;
+
+    /// Other accepted names for the exercise
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Parse the input into the model solved by both parts
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error>;
+
+    /// Short description of the expected input, shown as a hint when the input is malformed
+    fn input_format(&self) -> &'static str;
+
+    /// Puzzle constants which can be overridden from the command line, read from `Input::params`
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    /// Version of the answers of the solver, part of the key of the cached answers.
+    ///
+    /// Bump it when a change of the solver changes its answers, so that the answers cached by `all` are solved again.
+    fn version(&self) -> &'static str {
+        "1"
+    }
+
+    /// Named inputs of the exercise, the example of the puzzle statement and the real input by default
+    fn input_sets(&self) -> &'static [InputSet] {
+        &[InputSet::EXAMPLE, InputSet::REAL]
+    }
+
+    fn solve(&self, part: &Part, input: &Input) -> Result<Answer, Error> {
+        self.parse(input)
+            .and_then(|puzzle| puzzle.solve(part, input))
+            .map_err(|e| self.locate(e, input))
+    }
+
+    /// Solve both parts, the input being parsed only once
+    fn solve_both(&self, input: &Input) -> Result<(Answer, Answer), Error> {
+        let both = || {
+            let puzzle = self.parse(input)?;
+            Ok((puzzle.part_1(input)?, puzzle.part_2(input)?))
+        };
+        both().map_err(|e| self.locate(e, input))
+    }
+
+    /// Attach the input file and the expected format to an error of the solver
+    fn locate(&self, error: Error, input: &Input) -> Error {
+        error.in_file(&input.path).with_hint(self.input_format())
+    }
+
+    /// Every name selecting the exercise: its name, its module, its title and its aliases
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec![self.name(), self.module(), self.title()];
+        names.extend(self.aliases());
+        names
+    }
+
+    /// Whether the exercise is selected by `name`, either its day or one of its names regardless of the case
+    /// and of the separators
+    fn matches(&self, name: &str) -> bool {
+        if let Ok(day) = name.parse::<u8>() {
+            return day == self.day();
+        }
+        let name = normalize_name(name);
+        self.names().iter().any(|n| normalize_name(n) == name)
+    }
+}
+
+/// Input of a day once parsed, from which both parts are solved
+pub trait Puzzle {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error>;
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error>;
+
+    fn solve(&self, part: &Part, input: &Input) -> Result<Answer, Error> {
+        match part {
+            Part::Part1 => self.part_1(input),
+            Part::Part2 => self.part_2(input),
+        }
+    }
+}
+
+/// Input given to a solver
+pub struct Input {
+    /// Path the input has been read from, `-` for the standard input
+    pub path: String,
+    pub contents: String,
+    /// Input set the input belongs to, which gives the defaults of some puzzle parameters
+    pub input_set: InputSet,
+    /// Overridden puzzle parameters
+    pub params: Params,
+    /// Cancellation of the run, to be checked by the main loops of the solver
+    pub cancel: CancelToken,
+}
+
+impl Input {
+    pub fn new(path: &str, contents: &str, input_set: InputSet) -> Self {
+        Input {
+            path: path.to_string(),
+            contents: contents.to_string(),
+            input_set,
+            params: Params::default(),
+            cancel: CancelToken::default(),
+        }
+    }
+
+    pub fn with_params(mut self, params: Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Read the input from a file, or from the standard input if the path is `-`
+    pub fn read(path: &str, input_set: InputSet) -> Result<Self, Error> {
+        let contents = if path == "-" {
+            let mut contents = String::new();
+            io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(|e| Error::io("stdin", e))?;
+            contents
+        } else {
+            input_dir::read(path)?
+        };
+        Ok(Input {
+            path: path.to_string(),
+            contents,
+            input_set,
+            params: Params::default(),
+            cancel: CancelToken::default(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Solutions of one year of the Advent of code
+pub struct Year {
+    pub year: u16,
+    /// Registered solvers of the year, in the ascending order of the days
+    pub solvers: &'static [&'static dyn Solver],
+}
+
+/// Years having solutions, in the ascending order
+pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    solvers: crate::year2022::REGISTRY,
+}];
+
+impl Year {
+    /// Latest year having solutions, chosen when no year is given
+    pub fn latest() -> &'static Year {
+        YEARS.last().expect("At least one year has solutions")
+    }
+
+    /// Find a year having solutions
+    pub fn get(year: u16) -> Result<&'static Year, Error> {
+        YEARS.iter().find(|y| y.year == year).ok_or_else(|| {
+            Error::usage(format!(
+                "Unknown year {}, expected one of {}",
+                year,
+                YEARS
+                    .iter()
+                    .map(|y| y.year.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))
+        })
+    }
+
+    pub fn find_solver(&self, name: &str) -> Option<&'static dyn Solver> {
+        self.solvers.iter().find(|s| s.matches(name)).copied()
+    }
+
+    /// Find the solver of an exercise, the error suggests the closest exercises of the year when none matches
+    pub fn solver_named(&self, name: &str) -> Result<&'static dyn Solver, Error> {
+        if let Some(solver) = self.find_solver(name) {
+            return Ok(solver);
+        }
+        let suggestions = self
+            .suggest_solvers(name)
+            .iter()
+            .map(|s| format!("`{}` (day {}, {})", s.name(), s.day(), s.title()))
+            .collect::<Vec<String>>();
+        let message = match &suggestions[..] {
+            [] => format!(
+                "Unknown exercise chosen, please choose one of the available exercise of {}, got {}",
+                self.year, name
+            ),
+            [suggestion] => format!(
+                "Unknown exercise chosen, got {}, did you mean {}?",
+                name, suggestion
+            ),
+            _ => format!(
+                "Unknown exercise chosen, got {}, did you mean one of {}?",
+                name,
+                suggestions.join(", ")
+            ),
+        };
+        Err(Error::usage(message))
+    }
+
+    /// Solvers of the year having the names closest to `name`, a name being also close to its beginnings
+    pub fn suggest_solvers(&self, name: &str) -> Vec<&'static dyn Solver> {
+        const MAX_SUGGESTIONS: usize = 3;
+
+        let name = normalize_name(name);
+        let max_distance = (name.chars().count() / 3).max(1);
+        let candidates = self
+            .solvers
+            .iter()
+            .filter_map(|solver| {
+                let distance = solver
+                    .names()
+                    .iter()
+                    .map(|n| {
+                        let n = normalize_name(n);
+                        let prefix = n.chars().take(name.chars().count()).collect::<String>();
+                        edit_distance(&n, &name).min(edit_distance(&prefix, &name))
+                    })
+                    .min()?;
+                (distance <= max_distance).then_some((distance, *solver))
+            })
+            .collect::<Vec<(usize, &dyn Solver)>>();
+        let closest = candidates.iter().map(|(distance, _)| *distance).min();
+        candidates
+            .into_iter()
+            .filter(|(distance, _)| Some(*distance) == closest)
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, solver)| solver)
+            .collect()
+    }
+}
+
+/// Lowercase name whose words are separated by `-`, so that `Rope Bridge`, `rope_bridge` and `rope-bridge` are equal
+fn normalize_name(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .split([' ', '-', '_'])
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+/// Levenshtein distance between two strings, in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut previous_row = (0..=b.len()).collect::<Vec<usize>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != *b_char);
+            row.push(substitution.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::{self, Manifest};
+
+    fn year_2022() -> &'static Year {
+        Year::get(2022).unwrap()
+    }
+
+    #[test]
+    fn registry_is_ordered_by_year_and_day() {
+        for (i, year) in YEARS.iter().enumerate() {
+            assert!(i == 0 || YEARS[i - 1].year < year.year, "{}", year.year);
+            // Days may be missing, e.g. a day scaffolded before the previous one is solved
+            for (i, solver) in year.solvers.iter().enumerate() {
+                assert_eq!(solver.year(), year.year, "{}", solver.name());
+                assert!((1..=25).contains(&solver.day()), "{}", solver.name());
+                assert!(
+                    i == 0 || year.solvers[i - 1].day() < solver.day(),
+                    "{}",
+                    solver.name()
+                );
+            }
+        }
+        assert_eq!(Year::latest().year, YEARS[YEARS.len() - 1].year);
+        assert!(Year::get(2015).is_err());
+    }
+
+    #[test]
+    fn registry_names_and_aliases_are_unique() {
+        for year in YEARS {
+            let mut names = vec![];
+            for solver in year.solvers {
+                names.push(solver.name());
+                names.extend(solver.aliases());
+            }
+            let count = names.len();
+            names.sort_unstable();
+            names.dedup();
+            assert_eq!(names.len(), count);
+        }
+    }
+
+    /// The real input of a day still being solved, without any expected answer, may not be fetched yet
+    #[test]
+    fn registered_default_inputs_exist() {
+        for year in YEARS {
+            let manifest = Manifest::load(&manifest::default_path(year.year)).unwrap();
+            for solver in year.solvers {
+                for input_set in solver.input_sets() {
+                    if *input_set == InputSet::REAL && !manifest.has_answers(solver.day()) {
+                        continue;
+                    }
+                    let path = input_set.path(solver.year(), solver.day());
+                    assert!(input_dir::exists(&path), "{}", path);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn input_contents_are_solved_without_reading_a_file() {
+        let input = Input::new("inline", "4000\n\n1000\n2000\n", InputSet::REAL);
+        assert_eq!(
+            year_2022()
+                .find_solver("callories")
+                .unwrap()
+                .solve(&Part::Part1, &input)
+                .unwrap(),
+            Answer::Number(4000)
+        );
+    }
+
+    #[test]
+    fn both_parts_are_solved_from_one_parsing() {
+        let input = Input::new("inline", "4000\n\n1000\n2000\n\n500\n", InputSet::REAL);
+        assert_eq!(
+            year_2022()
+                .find_solver("callories")
+                .unwrap()
+                .solve_both(&input)
+                .unwrap(),
+            (Answer::Number(4000), Answer::Number(7500))
+        );
+    }
+
+    #[test]
+    fn reading_a_missing_input_file_fails_with_its_path() {
+        let err = Input::read("inputs/missing.txt", InputSet::REAL)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("inputs/missing.txt"));
+    }
+
+    #[test]
+    fn solvers_are_found_by_name_or_alias() {
+        assert_eq!(
+            year_2022().find_solver("distress-signal").unwrap().day(),
+            13
+        );
+        assert_eq!(
+            year_2022().find_solver("distress-signals").unwrap().day(),
+            13
+        );
+        assert!(year_2022().find_solver("unknown").is_none());
+    }
+
+    #[test]
+    fn registry_names_select_a_single_exercise() {
+        for year in YEARS {
+            for solver in year.solvers {
+                for name in solver.names() {
+                    assert!(name.parse::<u8>().is_err(), "{}", name);
+                    let selected = year.solvers.iter().filter(|s| s.matches(name)).count();
+                    assert_eq!(selected, 1, "{}", name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solvers_are_found_by_day_module_or_title() {
+        assert_eq!(
+            year_2022().find_solver("14").unwrap().name(),
+            "regolith-reservoir"
+        );
+        assert_eq!(year_2022().find_solver("01").unwrap().name(), "callories");
+        assert_eq!(
+            year_2022().find_solver("cathod_ray_tube").unwrap().day(),
+            10
+        );
+        assert_eq!(
+            year_2022().find_solver("Cathode-Ray Tube").unwrap().day(),
+            10
+        );
+        assert_eq!(
+            year_2022().find_solver("calorie-counting").unwrap().day(),
+            1
+        );
+        assert!(year_2022().find_solver("26").is_none());
+    }
+
+    #[test]
+    fn closest_solvers_are_suggested() {
+        let names = |name: &str| {
+            year_2022()
+                .suggest_solvers(name)
+                .iter()
+                .map(|s| s.name())
+                .collect::<Vec<&str>>()
+        };
+        assert_eq!(names("calories"), vec!["callories"]);
+        assert_eq!(names("rope-brige"), vec!["rope-bridge"]);
+        assert_eq!(names("monkey"), vec!["monkey-in-the-middle"]);
+        assert_eq!(names("distres"), vec!["distress-signal"]);
+        assert!(names("xyz").is_empty());
+
+        let error = year_2022()
+            .solver_named("rope-brige")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            error.contains("did you mean `rope-bridge` (day 9, Rope Bridge)?"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_insertions_deletions_and_substitutions() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("marker", "marker"), 0);
+    }
+}
@@ -0,0 +1,529 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::{config::Config, error::parse_json, Error, Part};
+
+/// Response of the website to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer has been given too recently, the next one is accepted after the duration
+    Wait(Duration),
+    /// The part has already been solved, the answer has not been checked
+    AlreadySolved,
+    /// Unrecognized response, with its text
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Recognize the outcome in the HTML page answered by the website
+    pub fn from_response(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            return Outcome::Right;
+        }
+        if page.contains("That's not the right answer") {
+            return if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            };
+        }
+        if page.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have ((?:\d+[hms] ?)+) left to wait")
+                .unwrap()
+                .captures(page)
+                .map(|captures| parse_wait(&captures[1]))
+                .unwrap_or(Duration::from_secs(60));
+            return Outcome::Wait(wait);
+        }
+        if page.contains("You don't seem to be solving the right level") {
+            return Outcome::AlreadySolved;
+        }
+        let article = Regex::new(r"(?s)<article>(.*?)</article>")
+            .unwrap()
+            .captures(page)
+            .map_or(page, |captures| captures.get(1).unwrap().as_str());
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(article, "");
+        Outcome::Unknown(text.split_whitespace().collect::<Vec<&str>>().join(" "))
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Right => "right",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wait(_) => "wait",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown(_) => "unknown",
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+/// Outcome of a submitted answer, with the time during which the website refuses another answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    /// Delay after a wrong answer before the next one is accepted, e.g. "please wait one minute before trying again"
+    pub lockout: Option<Duration>,
+}
+
+impl Submission {
+    /// Recognize the outcome and the lockout in the HTML page answered by the website
+    pub fn from_response(page: &str) -> Self {
+        let outcome = Outcome::from_response(page);
+        let lockout = if outcome.is_wrong() {
+            let lockout = Regex::new(r"(?i)wait (one|\d+) minutes? before trying again")
+                .unwrap()
+                .captures(page)
+                .and_then(|captures| match &captures[1] {
+                    "one" => Some(1),
+                    minutes => minutes.parse::<u64>().ok(),
+                })
+                .map_or(WRONG_ANSWER_LOCKOUT, |minutes| {
+                    Duration::from_secs(minutes * 60)
+                });
+            Some(lockout)
+        } else {
+            None
+        };
+        Submission { outcome, lockout }
+    }
+}
+
+/// Lockout assumed after a wrong answer when the response does not tell it, the shortest one of the website
+pub const WRONG_ANSWER_LOCKOUT: Duration = Duration::from_secs(60);
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "That's the right answer"),
+            Outcome::Wrong => write!(f, "That's not the right answer"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Outcome::Wait(wait) => write!(
+                f,
+                "An answer has been given too recently, wait {}s before trying again",
+                wait.as_secs()
+            ),
+            Outcome::AlreadySolved => write!(f, "The part has already been solved"),
+            Outcome::Unknown(text) => write!(f, "Unrecognized response: {}", text),
+        }
+    }
+}
+
+/// Parse a duration of the form `1h 2m 3s`
+fn parse_wait(text: &str) -> Duration {
+    let seconds = text
+        .split_whitespace()
+        .filter_map(|amount| {
+            let (value, unit) = amount.split_at(amount.len() - 1);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(value * 3_600),
+                "m" => Some(value * 60),
+                _ => Some(value),
+            }
+        })
+        .sum();
+    Duration::from_secs(seconds)
+}
+
+/// A submitted answer and the response of the website
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Time of the submission, in seconds since the Unix epoch
+    pub time: u64,
+    /// Time until which the website refuses another answer after a wrong one, in seconds since the Unix epoch
+    pub wait_until: Option<u64>,
+}
+
+impl Attempt {
+    fn to_json(&self) -> Value {
+        let mut value = json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part.to_string(),
+            "answer": self.answer,
+            "outcome": self.outcome.name(),
+            "time": self.time,
+        });
+        match &self.outcome {
+            Outcome::Wait(wait) => value["wait_seconds"] = json!(wait.as_secs()),
+            Outcome::Unknown(text) => value["text"] = json!(text),
+            _ => {}
+        }
+        if let Some(wait_until) = self.wait_until {
+            value["wait_until"] = json!(wait_until);
+        }
+        value
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let outcome = match value["outcome"].as_str()? {
+            "right" => Outcome::Right,
+            "wrong" => Outcome::Wrong,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "wait" => Outcome::Wait(Duration::from_secs(value["wait_seconds"].as_u64()?)),
+            "already_solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown(value["text"].as_str().unwrap_or("").to_string()),
+            _ => return None,
+        };
+        Some(Attempt {
+            year: u16::try_from(value["year"].as_u64()?).ok()?,
+            day: u8::try_from(value["day"].as_u64()?).ok()?,
+            part: Part::try_from(value["part"].as_str()?).ok()?,
+            answer: value["answer"].as_str()?.to_string(),
+            outcome,
+            time: value["time"].as_u64()?,
+            wait_until: value["wait_until"].as_u64(),
+        })
+    }
+}
+
+/// Every answer submitted to the website, kept in a file with one JSON object per line
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Path of the history, the `history_file` setting or else `history.jsonl` next to the configuration file
+    pub fn default_path(config: &Config) -> Result<PathBuf, Error> {
+        if let Some(path) = &config.history_file {
+            return Ok(path.clone());
+        }
+        Config::path()
+            .and_then(|path| path.parent().map(|dir| dir.join("history.jsonl")))
+            .ok_or(Error::usage(
+                "Unknown location of the answer history, set the `history_file` setting of the configuration file",
+            ))
+    }
+
+    /// Load the history, a missing file being an empty history
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let file = path.display().to_string();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::io(&file, e)),
+        };
+        let mut attempts = vec![];
+        for (idx, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let attempt = parse_json(line, "answer history")
+                .ok()
+                .as_ref()
+                .and_then(Attempt::from_json)
+                .ok_or_else(|| {
+                    Error::parse(line, "Invalid attempt in the answer history")
+                        .at_line(idx, line)
+                        .in_file(&file)
+                })?;
+            attempts.push(attempt);
+        }
+        Ok(History { path, attempts })
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |attempt| {
+            attempt.year == year && attempt.day == day && attempt.part == part
+        })
+    }
+
+    /// Refuse an answer which is known to be wrong, which is out of the bounds given by the previous attempts, or which
+    /// would be submitted too early after an attempt asking to wait or after a wrong answer
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Error> {
+        let refuse = |reason: String| {
+            Err(Error::usage(format!(
+                "Refusing to submit {} for day {} {}, {}",
+                answer, day, part, reason
+            )))
+        };
+        let attempts = self.attempts(year, day, part).collect::<Vec<&Attempt>>();
+
+        if let Some(right) = attempts.iter().find(|a| a.outcome == Outcome::Right) {
+            return refuse(format!("the part is already solved with {}", right.answer));
+        }
+        if let Some(wrong) = attempts
+            .iter()
+            .find(|a| a.answer == answer && a.outcome.is_wrong())
+        {
+            return refuse(format!("it has already been submitted: {}", wrong.outcome));
+        }
+        if let Ok(value) = answer.parse::<i64>() {
+            let bound = |outcome: Outcome| {
+                attempts
+                    .iter()
+                    .filter(move |a| a.outcome == outcome)
+                    .filter_map(|a| a.answer.parse::<i64>().ok())
+            };
+            if let Some(too_high) = bound(Outcome::TooHigh).min().filter(|high| value >= *high) {
+                return refuse(format!("{} is already too high", too_high));
+            }
+            if let Some(too_low) = bound(Outcome::TooLow).max().filter(|low| value <= *low) {
+                return refuse(format!("{} is already too low", too_low));
+            }
+        }
+        let wait_until = attempts
+            .iter()
+            .filter_map(|a| match a.outcome {
+                Outcome::Wait(wait) => Some(a.time + wait.as_secs()),
+                _ => a.wait_until,
+            })
+            .max();
+        if let Some(wait_until) = wait_until.filter(|until| *until > now) {
+            return refuse(format!("wait {}s before trying again", wait_until - now));
+        }
+        Ok(())
+    }
+
+    /// Append an attempt to the history file
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Error> {
+        let file = self.path.display().to_string();
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| Error::io(&file, e))?;
+        }
+        let mut history = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| Error::io(&file, e))?;
+        writeln!(history, "{}", attempt.to_json()).map_err(|e| Error::io(&file, e))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn attempt(answer: &str, outcome: Outcome, time: u64) -> Attempt {
+        Attempt {
+            year: 2022,
+            day: 1,
+            part: Part::Part1,
+            answer: answer.to_string(),
+            outcome,
+            time,
+            wait_until: None,
+        }
+    }
+
+    fn history(attempts: Vec<Attempt>) -> History {
+        History {
+            path: PathBuf::from("history.jsonl"),
+            attempts,
+        }
+    }
+
+    #[test]
+    fn responses_of_the_website_are_recognized() {
+        let page = |text: &str| {
+            format!(
+                "<html><main><article><p>{}</p></article></main></html>",
+                text
+            )
+        };
+        assert_eq!(
+            Outcome::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Outcome::Right
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::from_response(&page("That's not the right answer.")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."
+            )),
+            Outcome::Wait(Duration::from_secs(83))
+        );
+        assert_eq!(
+            Outcome::from_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            Outcome::from_response(&page("Something <em>else</em>.")),
+            Outcome::Unknown("Something else.".to_string())
+        );
+    }
+
+    #[test]
+    fn known_wrong_answers_are_refused() {
+        let history = history(vec![attempt("42", Outcome::Wrong, 0)]);
+        assert!(history.check(2022, 1, Part::Part1, "42", 1_000).is_err());
+        assert!(history.check(2022, 1, Part::Part1, "43", 1_000).is_ok());
+        assert!(history.check(2022, 1, Part::Part2, "42", 1_000).is_ok());
+        assert!(history.check(2023, 1, Part::Part1, "42", 1_000).is_ok());
+    }
+
+    #[test]
+    fn answers_out_of_the_known_bounds_are_refused() {
+        let history = history(vec![
+            attempt("100", Outcome::TooHigh, 0),
+            attempt("10", Outcome::TooLow, 0),
+        ]);
+        let error = history
+            .check(2022, 1, Part::Part1, "150", 1_000)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Refusing to submit 150 for day 1 part_1, 100 is already too high"
+        );
+        assert!(history.check(2022, 1, Part::Part1, "10", 1_000).is_err());
+        assert!(history.check(2022, 1, Part::Part1, "50", 1_000).is_ok());
+    }
+
+    #[test]
+    fn submissions_wait_for_the_delay_asked_by_the_website() {
+        let waiting = history(vec![attempt(
+            "42",
+            Outcome::Wait(Duration::from_secs(60)),
+            1_000,
+        )]);
+        assert!(waiting.check(2022, 1, Part::Part1, "42", 1_030).is_err());
+        assert!(waiting.check(2022, 1, Part::Part1, "42", 1_060).is_ok());
+
+        let solved = history(vec![attempt("42", Outcome::Right, 0)]);
+        assert!(solved.check(2022, 1, Part::Part1, "43", 1_060).is_err());
+    }
+
+    #[test]
+    fn lockout_after_a_wrong_answer_is_recognized() {
+        let lockout = |text: &str| Submission::from_response(text).lockout;
+        assert_eq!(
+            lockout("That's not the right answer. Please wait one minute before trying again."),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            lockout("That's not the right answer; your answer is too low. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            lockout("That's not the right answer."),
+            Some(WRONG_ANSWER_LOCKOUT)
+        );
+        assert_eq!(lockout("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn submissions_wait_for_the_lockout_after_a_wrong_answer() {
+        let history = history(vec![Attempt {
+            wait_until: Some(1_060),
+            ..attempt("42", Outcome::TooLow, 1_000)
+        }]);
+        let error = history
+            .check(2022, 1, Part::Part1, "50", 1_030)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Refusing to submit 50 for day 1 part_1, wait 30s before trying again"
+        );
+        assert!(history.check(2022, 1, Part::Part1, "50", 1_060).is_ok());
+    }
+
+    #[test]
+    fn attempts_are_kept_in_the_history_file() {
+        let path = env::temp_dir().join(format!(
+            "advent-of-code-history-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(path.clone()).unwrap();
+        history
+            .record(Attempt {
+                wait_until: Some(61),
+                ..attempt("42", Outcome::TooHigh, 1)
+            })
+            .unwrap();
+        history
+            .record(attempt("7", Outcome::Wait(Duration::from_secs(30)), 2))
+            .unwrap();
+
+        let history = History::load(path.clone()).unwrap();
+        assert_eq!(
+            history
+                .attempts(2022, 1, Part::Part1)
+                .cloned()
+                .collect::<Vec<Attempt>>(),
+            vec![
+                Attempt {
+                    wait_until: Some(61),
+                    ..attempt("42", Outcome::TooHigh, 1)
+                },
+                attempt("7", Outcome::Wait(Duration::from_secs(30)), 2)
+            ]
+        );
+
+        assert_eq!(history.attempts(2023, 1, Part::Part1).count(), 0);
+
+        // The year of an attempt is required
+        let mut content = fs::read_to_string(&path).unwrap();
+        content.push_str(r#"{"day":1,"part":"part_1","answer":"12","outcome":"too_low","time":3}"#);
+        fs::write(&path, content).unwrap();
+        assert!(History::load(path.clone()).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
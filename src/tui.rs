@@ -0,0 +1,448 @@
+use std::{
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, List, ListItem, ListState, Paragraph},
+    DefaultTerminal, Frame,
+};
+
+use crate::{
+    config::Config,
+    input_dir, input_set,
+    log::{self, LogBuffer},
+    manifest::Manifest,
+    runner::{self, RunReport},
+    submit::History,
+    warn, watch, CancelToken, Error, InputSet, Part, Solver, Year,
+};
+
+/// Interval between two refreshes of the screen, which updates the time of a running part
+const TICK: Duration = Duration::from_millis(100);
+
+/// Number of the last messages kept in the log pane
+const LOG_LINES: usize = 1_000;
+
+const KEYS_HELP: &str = " ↑/↓ day   ←/→ input set   1/2 part   Enter run   c cancel   q quit";
+
+/// Part running on a background thread
+struct Run {
+    solver: &'static dyn Solver,
+    part: Part,
+    input_set: InputSet,
+    started: Instant,
+    cancel: CancelToken,
+    report: Receiver<RunReport>,
+}
+
+/// State of the dashboard of a year
+pub struct App {
+    year: &'static Year,
+    /// Index of the selected day among the solvers of the year
+    selected: usize,
+    /// Index of the chosen input set among the input sets of the selected day
+    input_set: usize,
+    part: Part,
+    manifest: Option<Manifest>,
+    history: Option<History>,
+    /// Messages logged by the dashboard and by the runs
+    log: LogBuffer,
+    run: Option<Run>,
+    /// Description of the last finished or cancelled run
+    last_run: Vec<String>,
+    quit: bool,
+}
+
+impl App {
+    /// Dashboard of the year, the answer manifest and the answer history being loaded once, a failure being logged
+    pub fn new(year: &'static Year, log: LogBuffer) -> Result<Self, Error> {
+        if year.solvers.is_empty() {
+            return Err(Error::usage(format!(
+                "No exercise registered for {}, add one with `cargo run --year {} new <day> <name>`",
+                year.year, year.year
+            )));
+        }
+        let manifest = Manifest::load(&crate::manifest::default_path(year.year))
+            .map_err(|e| warn!("No expected answers, {}", e))
+            .ok();
+        let history = Config::load()
+            .and_then(|config| History::default_path(&config))
+            .and_then(History::load)
+            .map_err(|e| warn!("No answer history, {}", e))
+            .ok();
+        let mut app = App {
+            year,
+            selected: 0,
+            input_set: 0,
+            part: Part::Part1,
+            manifest,
+            history,
+            log,
+            run: None,
+            last_run: vec![],
+            quit: false,
+        };
+        app.select(0);
+        Ok(app)
+    }
+
+    fn solver(&self) -> &'static dyn Solver {
+        self.year.solvers[self.selected]
+    }
+
+    fn chosen_input_set(&self) -> InputSet {
+        self.solver().input_sets()[self.input_set]
+    }
+
+    /// Select a day, the real input being chosen by default
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.input_set = self
+            .solver()
+            .input_sets()
+            .iter()
+            .position(|set| *set == InputSet::REAL)
+            .unwrap_or(0);
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        let count = self.year.solvers.len();
+        let input_sets = self.solver().input_sets().len();
+        match key.code {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.select((self.selected + count - 1) % count),
+            KeyCode::Down | KeyCode::Char('j') => self.select((self.selected + 1) % count),
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.input_set = (self.input_set + input_sets - 1) % input_sets
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.input_set = (self.input_set + 1) % input_sets
+            }
+            KeyCode::Char('1') => self.part = Part::Part1,
+            KeyCode::Char('2') => self.part = Part::Part2,
+            KeyCode::Tab => {
+                self.part = match self.part {
+                    Part::Part1 => Part::Part2,
+                    Part::Part2 => Part::Part1,
+                }
+            }
+            KeyCode::Enter | KeyCode::Char('r') => self.start_run(),
+            KeyCode::Char('c') | KeyCode::Esc => self.cancel_run(),
+            _ => {}
+        }
+    }
+
+    /// Run the chosen part of the selected day on a background thread, which logs into the dashboard
+    fn start_run(&mut self) {
+        if self.run.is_some() {
+            return;
+        }
+        let (solver, part, input_set) = (self.solver(), self.part, self.chosen_input_set());
+        let (sender, report) = mpsc::channel();
+        let log = self.log.clone();
+        let cancel = CancelToken::default();
+        let run_cancel = cancel.clone();
+        thread::spawn(move || {
+            log::capture(Some(log));
+            // The receiver is gone when the run was cancelled, its report is then dropped
+            let _ = sender.send(runner::run_part(solver, part, input_set, run_cancel));
+        });
+        self.run = Some(Run {
+            solver,
+            part,
+            input_set,
+            started: Instant::now(),
+            cancel,
+            report,
+        });
+    }
+
+    /// Cancel the running part and stop waiting for it.
+    ///
+    /// The run stops at the next check of its cancellation by the solver, the thread of a solver which does not check
+    /// it keeps going in the background until it ends.
+    fn cancel_run(&mut self) {
+        if let Some(run) = self.run.take() {
+            run.cancel.cancel();
+            self.last_run = vec![format!(
+                "{} of day {} on {} cancelled after {}",
+                run.part,
+                run.solver.day(),
+                run.input_set,
+                runner::format_duration(run.started.elapsed())
+            )];
+        }
+    }
+
+    /// Collect the report of the running part once it is done
+    fn check_run(&mut self) {
+        let Some(run) = &self.run else {
+            return;
+        };
+        let report = match run.report.try_recv() {
+            Ok(report) => report,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                self.last_run = vec![format!("{} of day {} panicked", run.part, run.solver.day())];
+                self.run = None;
+                return;
+            }
+        };
+        let expected = self
+            .manifest
+            .as_ref()
+            .and_then(|m| m.expected(report.day, run.input_set.name, report.part));
+        self.last_run = vec![
+            format!("Day {} {} on {}:", report.day, report.name, run.input_set),
+            watch::format_report(&report, expected),
+        ];
+        self.run = None;
+    }
+
+    /// Number of the input files found and of the answers known, over the input sets of the solver
+    fn status(&self, solver: &dyn Solver) -> String {
+        let input_sets = solver.input_sets();
+        let inputs = input_sets
+            .iter()
+            .filter(|set| input_dir::exists(&set.path(solver.year(), solver.day())))
+            .count();
+        let answers = input_sets
+            .iter()
+            .flat_map(|set| [Part::Part1, Part::Part2].map(|part| (set, part)))
+            .filter(|(set, part)| {
+                self.manifest
+                    .as_ref()
+                    .is_some_and(|m| m.expected(solver.day(), set.name, *part).is_some())
+            })
+            .count();
+        format!(
+            "inputs {}/{}  answers {}/{}",
+            inputs,
+            input_sets.len(),
+            answers,
+            2 * input_sets.len()
+        )
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let [main, keys] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [days, right] =
+            Layout::horizontal([Constraint::Length(56), Constraint::Min(0)]).areas(main);
+        let [details, run, bottom] = Layout::vertical([
+            Constraint::Percentage(40),
+            Constraint::Length(7),
+            Constraint::Min(5),
+        ])
+        .areas(right);
+        let [log_area, history_area] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(bottom);
+
+        self.render_days(frame, days);
+        let details_text = match &self.manifest {
+            Some(manifest) => input_set::format_input_sets(self.solver(), manifest),
+            None => String::from("No answer manifest"),
+        };
+        frame.render_widget(
+            Paragraph::new(details_text).block(Block::bordered().title(" Input sets ")),
+            details,
+        );
+        frame.render_widget(
+            Paragraph::new(self.run_lines().join("\n")).block(Block::bordered().title(" Run ")),
+            run,
+        );
+        let log = self
+            .log
+            .last_lines(log_area.height.saturating_sub(2) as usize);
+        frame.render_widget(
+            Paragraph::new(log.join("\n")).block(Block::bordered().title(" Log ")),
+            log_area,
+        );
+        frame.render_widget(
+            Paragraph::new(self.history_lines().join("\n"))
+                .block(Block::bordered().title(" Answer history ")),
+            history_area,
+        );
+        frame.render_widget(Paragraph::new(KEYS_HELP), keys);
+    }
+
+    fn render_days(&self, frame: &mut Frame, area: Rect) {
+        let items = self
+            .year
+            .solvers
+            .iter()
+            .map(|solver| {
+                ListItem::new(format!(
+                    "{:>2} {:<22} {}",
+                    solver.day(),
+                    solver.name(),
+                    self.status(*solver)
+                ))
+            })
+            .collect::<Vec<ListItem>>();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" Advent of code {} ", self.year.year)))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn run_lines(&self) -> Vec<String> {
+        let chosen = format!(
+            "Day {} {}, {} on {}",
+            self.solver().day(),
+            self.solver().name(),
+            self.part,
+            self.chosen_input_set()
+        );
+        let mut lines = vec![chosen, String::new()];
+        match &self.run {
+            Some(run) => lines.push(format!(
+                "Running {} of day {} on {} for {}, press c to cancel",
+                run.part,
+                run.solver.day(),
+                run.input_set,
+                runner::format_duration(run.started.elapsed())
+            )),
+            None if self.last_run.is_empty() => lines.push("Press Enter to run".to_string()),
+            None => lines.extend(self.last_run.iter().cloned()),
+        }
+        lines
+    }
+
+    /// Answers submitted for the selected day
+    fn history_lines(&self) -> Vec<String> {
+        let Some(history) = &self.history else {
+            return vec!["No answer history".to_string()];
+        };
+        let solver = self.solver();
+        let lines = [Part::Part1, Part::Part2]
+            .iter()
+            .flat_map(|part| history.attempts(solver.year(), solver.day(), *part))
+            .map(|attempt| format!("{}: {}, {}", attempt.part, attempt.answer, attempt.outcome))
+            .collect::<Vec<String>>();
+        if lines.is_empty() {
+            return vec!["No answer submitted".to_string()];
+        }
+        lines
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> Result<(), Error> {
+        let terminal_error = |e| Error::io("terminal", e);
+        while !self.quit {
+            self.check_run();
+            terminal
+                .draw(|frame| self.render(frame))
+                .map_err(terminal_error)?;
+            if !event::poll(TICK).map_err(terminal_error)? {
+                continue;
+            }
+            if let Event::Key(key) = event::read().map_err(terminal_error)? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Show the dashboard of the year until it is quit, the log being captured in a pane of the dashboard
+pub fn run(year: &'static Year) -> Result<(), Error> {
+    let log = LogBuffer::bounded(LOG_LINES);
+    log::capture(Some(log.clone()));
+    let mut app = match App::new(year, log.clone()) {
+        Ok(app) => app,
+        Err(e) => {
+            log::capture(None);
+            return Err(e);
+        }
+    };
+    let mut terminal = ratatui::init();
+    let result = app.event_loop(&mut terminal);
+    ratatui::restore();
+    log::capture(None);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn app() -> App {
+        App::new(Year::get(2022).unwrap(), LogBuffer::new()).unwrap()
+    }
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(140, 40)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn keys_choose_the_day_the_input_set_and_the_part() {
+        let mut app = app();
+        assert_eq!(app.solver().day(), 1);
+        assert_eq!(app.chosen_input_set(), InputSet::REAL);
+        app.handle_key(KeyCode::Up.into());
+        assert_eq!(app.selected, app.year.solvers.len() - 1);
+        for _ in 0..9 {
+            app.handle_key(KeyCode::Down.into());
+        }
+        assert_eq!(app.solver().name(), "rope-bridge");
+        assert_eq!(app.chosen_input_set(), InputSet::REAL);
+        app.handle_key(KeyCode::Right.into());
+        assert_eq!(app.chosen_input_set().name, "example");
+        app.handle_key(KeyCode::Char('2').into());
+        assert_eq!(app.part, Part::Part2);
+        app.handle_key(KeyCode::Char('q').into());
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn runs_report_their_answer_on_the_dashboard() {
+        let mut app = app();
+        app.handle_key(KeyCode::Left.into());
+        assert_eq!(app.chosen_input_set(), InputSet::EXAMPLE);
+        app.handle_key(KeyCode::Enter.into());
+        assert!(screen(&app).contains("Running part_1 of day 1 on example"));
+        while app.run.is_some() {
+            thread::sleep(Duration::from_millis(10));
+            app.check_run();
+        }
+        assert!(app.last_run[1].starts_with("part_1: 24000 in "));
+        assert!(app.last_run[1].ends_with("matches the expected answer"));
+
+        let screen = screen(&app);
+        assert!(screen.contains("Advent of code 2022"));
+        assert!(screen.contains(" 9 rope-bridge"));
+        assert!(screen.contains("Input sets of callories (day 1 of 2022):"));
+        assert!(screen.contains("matches the expected answer"));
+    }
+
+    #[test]
+    fn cancelled_runs_are_abandoned() {
+        let mut app = app();
+        app.handle_key(KeyCode::Char('c').into());
+        assert!(app.last_run.is_empty());
+        app.handle_key(KeyCode::Left.into());
+        app.handle_key(KeyCode::Enter.into());
+        app.handle_key(KeyCode::Esc.into());
+        assert!(app.run.is_none());
+        assert!(app.last_run[0].starts_with("part_1 of day 1 on example cancelled after"));
+    }
+}
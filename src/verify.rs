@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use crate::{log, manifest::Manifest, runner, Answer, CancelToken, Part, Solver};
+
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+pub struct Verification {
+    pub day: u8,
+    pub name: &'static str,
+    pub input_set: &'static str,
+    pub part: Part,
+    pub verdict: Verdict,
+    /// Messages logged by the run when the runs are parallel, captured to be shown in the order of the verifications
+    pub log: Vec<String>,
+}
+
+/// Run every solver on every input set it declares, or only on the named one, and compare the answers with the
+/// manifest, each run being on one of `jobs` threads and stopped after `timeout` if any.
+///
+/// Entries without an expected answer are reported as missing and are not run. The verifications are in the order of
+/// the solvers, of their input sets and of the parts.
+pub fn verify(
+    solvers: &[&dyn Solver],
+    manifest: &Manifest,
+    only_input_set: Option<&str>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Verification> {
+    let mut entries = vec![];
+    for solver in solvers {
+        let input_sets = solver
+            .input_sets()
+            .iter()
+            .filter(|set| only_input_set.is_none_or(|name| set.name == name));
+        for input_set in input_sets {
+            for part in [Part::Part1, Part::Part2] {
+                entries.push((*solver, *input_set, part));
+            }
+        }
+    }
+    runner::parallel_map(&entries, jobs, |(solver, input_set, part)| {
+        let (verdict, log) = log::captured_if(jobs > 1, || {
+            match manifest.expected(solver.day(), input_set.name, *part) {
+                None => Verdict::Missing,
+                Some(expected) => {
+                    let report =
+                        runner::run_part(*solver, *part, *input_set, CancelToken::new(timeout));
+                    match report.result {
+                        Err(e) => Verdict::Fail(format!("error: {}", e)),
+                        Ok(answer) if &answer == expected => Verdict::Pass,
+                        Ok(answer) => Verdict::Fail(diff(expected, &answer)),
+                    }
+                }
+            }
+        });
+        Verification {
+            day: solver.day(),
+            name: solver.name(),
+            input_set: input_set.name,
+            part: *part,
+            verdict,
+            log,
+        }
+    })
+}
+
+/// Describe the difference between two answers, string answers are compared line by line
+pub fn diff(expected: &Answer, got: &Answer) -> String {
+    match (expected, got) {
+        (Answer::Text(expected), Answer::Text(got)) => {
+            let expected_lines = expected.lines().collect::<Vec<&str>>();
+            let got_lines = got.lines().collect::<Vec<&str>>();
+            let mut lines = vec![];
+            for i in 0..std::cmp::max(expected_lines.len(), got_lines.len()) {
+                let expected_line = expected_lines.get(i);
+                let got_line = got_lines.get(i);
+                if expected_line == got_line {
+                    lines.push(format!("  {}", expected_line.unwrap_or(&"")));
+                    continue;
+                }
+                if let Some(line) = expected_line {
+                    lines.push(format!("- {}", line));
+                }
+                if let Some(line) = got_line {
+                    lines.push(format!("+ {}", line));
+                }
+            }
+            lines.join("\n")
+        }
+        (expected, got) => format!("expected {}, got {}", expected, got),
+    }
+}
+
+pub fn format_report(verifications: &[Verification]) -> String {
+    let name_width = verifications
+        .iter()
+        .map(|v| v.name.len())
+        .max()
+        .unwrap_or(0);
+    let input_set_width = verifications
+        .iter()
+        .map(|v| v.input_set.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = vec![];
+    for verification in verifications {
+        let status = match verification.verdict {
+            Verdict::Pass => "PASS",
+            Verdict::Fail(_) => "FAIL",
+            Verdict::Missing => "MISSING",
+        };
+        lines.push(format!(
+            "{:<7} day {:<2} {:<name_width$} {:<input_set_width$} {}",
+            status,
+            verification.day,
+            verification.name,
+            verification.input_set,
+            verification.part,
+            name_width = name_width,
+            input_set_width = input_set_width
+        ));
+        if let Verdict::Fail(details) = &verification.verdict {
+            for line in details.lines() {
+                lines.push(format!("        {}", line));
+            }
+        }
+    }
+
+    let count = |f: fn(&Verdict) -> bool| verifications.iter().filter(|v| f(&v.verdict)).count();
+    lines.push(format!(
+        "\n{} passed, {} failed, {} missing",
+        count(|v| matches!(v, Verdict::Pass)),
+        count(|v| matches!(v, Verdict::Fail(_))),
+        count(|v| matches!(v, Verdict::Missing)),
+    ));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Year;
+
+    #[test]
+    fn answers_are_checked_against_the_manifest() {
+        let manifest =
+            Manifest::try_from(r#"{ "1": { "example": { "part_1": 24000, "part_2": 1 } } }"#)
+                .unwrap();
+        let verifications = verify(
+            &[Year::get(2022).unwrap().find_solver("callories").unwrap()],
+            &manifest,
+            None,
+            2,
+            None,
+        );
+        let verdicts = verifications
+            .iter()
+            .map(|v| match &v.verdict {
+                Verdict::Pass => "pass".to_string(),
+                Verdict::Fail(details) => details.to_string(),
+                Verdict::Missing => "missing".to_string(),
+            })
+            .collect::<Vec<String>>();
+        assert_eq!(
+            verdicts,
+            vec!["pass", "expected 1, got 45000", "missing", "missing"]
+        );
+    }
+
+    #[test]
+    fn text_answers_are_diffed_line_by_line() {
+        assert_eq!(
+            diff(
+                &Answer::Text("#..#\n.##.\n".to_string()),
+                &Answer::Text("#..#\n.#..\n".to_string())
+            ),
+            "  #..#\n- .##.\n+ .#.."
+        );
+    }
+}
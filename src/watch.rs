@@ -0,0 +1,169 @@
+use std::{
+    fs, thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    input_dir, input_key,
+    runner::{self, RunReport},
+    verify, Answer, Error,
+};
+
+/// Interval between two checks of the watched input file
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Input file polled for changes, a change being a new modification time or a new size
+pub struct InputWatcher {
+    path: String,
+    stamp: Option<(SystemTime, u64)>,
+}
+
+impl InputWatcher {
+    /// Watch the input file, an input found only encrypted being watched through its encrypted file
+    pub fn new(path: &str) -> Result<Self, Error> {
+        if path == "-" || path.starts_with(input_dir::EMBEDDED_PREFIX) {
+            return Err(Error::usage(format!(
+                "`--watch` needs an input file to poll, got {}",
+                path
+            )));
+        }
+        let path = if input_dir::is_encrypted(path) {
+            input_key::encrypted_path(path)
+        } else {
+            path.to_string()
+        };
+        let stamp = stamp(&path);
+        Ok(InputWatcher { path, stamp })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Whether the file changed since the last check.
+    ///
+    /// A missing file is not a change, so that a file replaced by an editor is only seen once it is written again.
+    pub fn has_changed(&mut self) -> bool {
+        match stamp(&self.path) {
+            Some(stamp) if Some(stamp) != self.stamp => {
+                self.stamp = Some(stamp);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Block until the file changes
+    pub fn wait_for_change(&mut self) {
+        while !self.has_changed() {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn stamp(path: &str) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Describe a run of the watch mode: the answer, the time of the run and whether it is the expected answer
+pub fn format_report(report: &RunReport, expected: Option<&Answer>) -> String {
+    let time = runner::format_duration(report.elapsed);
+    let answer = match &report.result {
+        Ok(answer) => answer,
+        Err(e) => return format!("{}: failed in {}, {}", report.part, time, e),
+    };
+    let (status, details) = match expected {
+        None => ("no expected answer", None),
+        Some(expected) if expected == answer => ("matches the expected answer", None),
+        Some(expected) => (
+            "does not match the expected answer",
+            Some(verify::diff(expected, answer)),
+        ),
+    };
+    let mut lines = match answer {
+        Answer::Text(text) if text.contains('\n') => {
+            let mut lines = vec![format!("{} in {}, {}", report.part, time, status)];
+            if details.is_none() {
+                lines.extend(text.lines().map(|line| format!("    {}", line)));
+            }
+            lines
+        }
+        answer => vec![format!(
+            "{}: {} in {}, {}",
+            report.part, answer, time, status
+        )],
+    };
+    if let Some(details) = details {
+        lines.extend(details.lines().map(|line| format!("    {}", line)));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    fn report(result: Result<Answer, String>) -> RunReport {
+        RunReport {
+            year: 2022,
+            day: 14,
+            name: "regolith-reservoir",
+            part: Part::Part1,
+            input_path: "inputs/2022/input-14-example.txt".to_string(),
+            result,
+            elapsed: Duration::from_millis(2),
+            log: vec![],
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn runs_are_compared_with_the_expected_answer() {
+        let expected = Answer::Number(24);
+        assert_eq!(
+            format_report(&report(Ok(Answer::Number(24))), Some(&expected)),
+            "part_1: 24 in 2.000ms, matches the expected answer"
+        );
+        assert_eq!(
+            format_report(&report(Ok(Answer::Number(25))), Some(&expected)),
+            "part_1: 25 in 2.000ms, does not match the expected answer\n    expected 24, got 25"
+        );
+        assert_eq!(
+            format_report(&report(Ok(Answer::Number(25))), None),
+            "part_1: 25 in 2.000ms, no expected answer"
+        );
+        assert_eq!(
+            format_report(&report(Err("no path found".to_string())), Some(&expected)),
+            "part_1: failed in 2.000ms, no path found"
+        );
+        assert_eq!(
+            format_report(&report(Ok(Answer::Text("#.\n.#\n".to_string()))), None),
+            "part_1 in 2.000ms, no expected answer\n    #.\n    .#"
+        );
+    }
+
+    #[test]
+    fn changes_of_the_input_file_are_detected() {
+        let path = std::env::temp_dir()
+            .join(format!("advent-of-code-watch-{}.txt", std::process::id()))
+            .display()
+            .to_string();
+        fs::write(&path, "498,4 -> 498,6\n").unwrap();
+        let mut watcher = InputWatcher::new(&path).unwrap();
+        assert!(!watcher.has_changed());
+
+        fs::write(&path, "498,4 -> 498,6 -> 496,6\n").unwrap();
+        assert!(watcher.has_changed());
+        assert!(!watcher.has_changed());
+
+        fs::remove_file(&path).unwrap();
+        assert!(!watcher.has_changed());
+        fs::write(&path, "503,4 -> 502,4\n").unwrap();
+        assert!(watcher.has_changed());
+        fs::remove_file(&path).unwrap();
+
+        assert!(InputWatcher::new("-").is_err());
+    }
+}
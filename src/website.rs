@@ -0,0 +1,386 @@
+use std::{
+    env, fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    config::Config,
+    debug, info,
+    input_dir::{self, InputDir, INPUT_DIR_ENV_VAR},
+    input_key,
+    submit::Submission,
+    Error, InputSet, Part,
+};
+
+/// Environment variable giving the session token, which takes precedence over the configuration
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Environment variable giving the address of the website, which takes precedence over the configuration
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimum time between two requests to the website, whatever the process sending them
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Client of the Advent of code website, authenticated by the session token of the user
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    rate_limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(
+        base_url: &str,
+        session: &str,
+        contact: Option<&str>,
+        rate_limiter: RateLimiter,
+    ) -> Self {
+        let mut user_agent = format!(
+            "{}/{} (command line puzzle runner",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        );
+        if let Some(contact) = contact {
+            user_agent.push_str(&format!(", contact {}", contact));
+        }
+        user_agent.push(')');
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent,
+            rate_limiter,
+        }
+    }
+
+    /// Client configured by the environment variables, or else by the configuration file
+    pub fn from_env(config: &Config) -> Result<Self, Error> {
+        let session = env::var(SESSION_ENV_VAR)
+            .ok()
+            .or(config.session.clone())
+            .ok_or(Error::usage(format!(
+                "Missing session token, set the `{}` environment variable or the `session` setting of the configuration \
+                 file to the `session` cookie of a browser logged in on the website",
+                SESSION_ENV_VAR
+            )))?;
+        let base_url = env::var(BASE_URL_ENV_VAR)
+            .ok()
+            .or(config.base_url.clone())
+            .unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(
+            &base_url,
+            session.trim(),
+            config.contact.as_deref(),
+            RateLimiter::shared(),
+        ))
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    fn agent(&self) -> ureq::Agent {
+        ureq::AgentBuilder::new()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(&self.user_agent)
+            .build()
+    }
+
+    /// Download the puzzle input of a day
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = self.input_url(year, day);
+        self.rate_limiter.wait()?;
+        debug!("Downloading {}", url);
+        let response = self
+            .agent()
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| Error::http(&url, e.to_string())),
+            Err(ureq::Error::Status(404, _)) => Err(Error::http(
+                &url,
+                format!("the input of day {} of {} is not available yet", day, year),
+            )),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(Error::http(
+                    &url,
+                    format!(
+                        "status {}, {}, the session token may have expired",
+                        code,
+                        body.lines().next().unwrap_or("no details")
+                    ),
+                ))
+            }
+            Err(e) => Err(Error::http(&url, e.to_string())),
+        }
+    }
+
+    /// Submit the answer of a part, the response of the website is recognized by `Submission::from_response`
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Submission, Error> {
+        let url = self.answer_url(year, day);
+        let level = match part {
+            Part::Part1 => "1",
+            Part::Part2 => "2",
+        };
+        self.rate_limiter.wait()?;
+        debug!("Submitting {} to {}", answer, url);
+        let page = self
+            .agent()
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", level), ("answer", answer)])
+            .map_err(|e| Error::http(&url, e.to_string()))?
+            .into_string()
+            .map_err(|e| Error::http(&url, e.to_string()))?;
+        Ok(Submission::from_response(&page))
+    }
+}
+
+/// Spread the requests to the website, the time of the last request being kept in a file shared by every process
+pub struct RateLimiter {
+    stamp_path: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(stamp_path: PathBuf, interval: Duration) -> Self {
+        RateLimiter {
+            stamp_path,
+            interval,
+        }
+    }
+
+    /// Rate limiter shared by the processes of the user, allowing a request every `MIN_REQUEST_INTERVAL`
+    pub fn shared() -> Self {
+        RateLimiter::new(
+            env::temp_dir().join("advent-of-code-last-request"),
+            MIN_REQUEST_INTERVAL,
+        )
+    }
+
+    /// Wait until the interval since the last request has elapsed, then record the new request
+    pub fn wait(&self) -> Result<(), Error> {
+        let path = self.stamp_path.display().to_string();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let last_request = match fs::read_to_string(&self.stamp_path) {
+            Ok(content) => content.trim().parse::<u64>().ok().map(Duration::from_nanos),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(Error::io(&path, e)),
+        };
+        if let Some(remaining) = last_request
+            .and_then(|last| (last + self.interval).checked_sub(now))
+            .filter(|remaining| *remaining <= self.interval)
+        {
+            info!(
+                "Waiting {:.1}s before the next request to the website",
+                remaining.as_secs_f64()
+            );
+            thread::sleep(remaining);
+        }
+        let sent = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::write(&self.stamp_path, sent.as_nanos().to_string()).map_err(|e| Error::io(&path, e))
+    }
+}
+
+/// Outcome of fetching an input
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(String),
+    Cached(String),
+}
+
+/// Download the real input of a day into the folder of its year in the input directory, unless it is already there.
+///
+/// An empty input file, as created for a new day, is downloaded again.
+pub fn fetch(client: &Client, year: u16, day: u8, input_dir: &InputDir) -> Result<Fetched, Error> {
+    let InputDir::Directory(dir) = input_dir else {
+        return Err(Error::usage(format!(
+            "The inputs are embedded in the binary, set `{}` to the directory where the input should be saved",
+            INPUT_DIR_ENV_VAR
+        )));
+    };
+    let path = input_dir.path(&InputSet::REAL.relative_path(year, day));
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+    if input_dir::is_encrypted(&path) {
+        return Ok(Fetched::Cached(input_key::encrypted_path(&path)));
+    }
+
+    let input = client.fetch_input(year, day)?;
+    let year_dir = dir.join(year.to_string());
+    fs::create_dir_all(&year_dir).map_err(|e| Error::io(&year_dir.display().to_string(), e))?;
+    fs::write(&path, input).map_err(|e| Error::io(&path, e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        path::Path,
+        sync::mpsc,
+    };
+
+    /// Answer the next request on a local port with the given status and body, the request is sent back on the channel
+    fn stub_server(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut buffer = [0; 1024];
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.push_str(std::str::from_utf8(&buffer[..read]).unwrap());
+                let Some((headers, body)) = request.split_once("\r\n\r\n") else {
+                    continue;
+                };
+                let content_length = headers
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.parse::<usize>().unwrap());
+                if body.len() >= content_length {
+                    break;
+                }
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            sender.send(request).unwrap();
+        });
+        (base_url, receiver)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-of-code-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn client(base_url: &str, dir: &Path) -> Client {
+        let rate_limiter = RateLimiter::new(dir.join("last-request"), Duration::ZERO);
+        Client::new(base_url, "secret", Some("elf@example.com"), rate_limiter)
+    }
+
+    #[test]
+    fn input_is_downloaded_once_then_cached() {
+        let dir = temp_dir("fetch");
+        let (base_url, requests) = stub_server("200 OK", "1000\n2000\n");
+        let input_dir = InputDir::Directory(dir.join("inputs"));
+        let client = client(&base_url, &dir);
+
+        let path = input_dir.path("2023/input-01.txt");
+        assert_eq!(
+            fetch(&client, 2023, 1, &input_dir).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("GET /2023/day/1/input HTTP/1.1"),
+            "{}",
+            request
+        );
+        assert!(request.contains("Cookie: session=secret"), "{}", request);
+        assert!(request.contains("contact elf@example.com"), "{}", request);
+
+        // The stub only answers once, the second fetch would fail if it sent a request
+        assert_eq!(
+            fetch(&client, 2023, 1, &input_dir).unwrap(),
+            Fetched::Cached(path)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unavailable_input_is_reported() {
+        let dir = temp_dir("unavailable");
+        let (base_url, _requests) = stub_server("404 Not Found", "");
+        let input_dir = InputDir::Directory(dir.join("inputs"));
+
+        let error = fetch(&client(&base_url, &dir), 2022, 25, &input_dir)
+            .err()
+            .unwrap();
+        assert!(
+            error
+                .to_string()
+                .contains("the input of day 25 of 2022 is not available yet"),
+            "{}",
+            error
+        );
+        assert!(!dir
+            .join("inputs")
+            .join("2022")
+            .join("input-25.txt")
+            .exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn answer_is_posted_with_its_level() {
+        let dir = temp_dir("submit");
+        let (base_url, requests) = stub_server(
+            "200 OK",
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+
+        let submission = client(&base_url, &dir)
+            .submit_answer(2022, 5, Part::Part2, "12")
+            .unwrap();
+        assert_eq!(submission.outcome, crate::submit::Outcome::TooLow);
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("POST /2022/day/5/answer HTTP/1.1"),
+            "{}",
+            request
+        );
+        assert!(request.ends_with("level=2&answer=12"), "{}", request);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn requests_are_spread_by_the_rate_limiter() {
+        let dir = temp_dir("rate-limit");
+        let rate_limiter = RateLimiter::new(dir.join("last-request"), Duration::from_millis(200));
+        let start = std::time::Instant::now();
+        rate_limiter.wait().unwrap();
+        rate_limiter.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    error::{parse_value, ErrorCollector},
+    input_dir, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
+
+pub struct Assignment;
+
+impl Solver for Assignment {
+    fn name(&self) -> &'static str {
+        "assignement"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn module(&self) -> &'static str {
+        "assignment"
+    }
+
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_pairs(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one pair of section ranges per line, e.g. `2-4,6-8`"
+    }
+}
+
+/// Pairs of elves with their assignements
+pub struct Pairs {
+    pairs: Vec<Pair>,
+}
+
+impl Puzzle for Pairs {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .count_pairs(Pair::has_contained_assignements, &input.cancel)?
+            .into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .count_pairs(Pair::has_overlapping_assignemments, &input.cancel)?
+            .into())
+    }
+}
+
+impl Pairs {
+    /// Number of pairs matching the predicate
+    fn count_pairs(
+        &self,
+        predicate: impl Fn(&Pair) -> bool,
+        cancel: &CancelToken,
+    ) -> Result<u32, Error> {
+        let mut count = 0;
+        for (index, pair) in self.pairs.iter().enumerate() {
+            cancel.check(|| format!("{} of {} pairs checked", index, self.pairs.len()))?;
+            if predicate(pair) {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+pub fn parse_pairs(contents: &str) -> Result<Pairs, Error> {
+    let mut pairs = vec![];
+    let mut errors = ErrorCollector::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let Some(pair) =
+            errors.check(parse_line_into_pair(line).map_err(|e| e.at_line(index, line)))?
+        else {
+            continue;
+        };
+        pairs.push(pair);
+    }
+    errors.finish()?;
+
+    return Ok(Pairs { pairs });
+}
+
+pub fn count_fully_contained_assignement_in_pair(filename: &str) -> Result<u32, Error> {
+    let contents = input_dir::read(filename)?;
+    count_fully_contained_assignement_in_pair_from_input(&contents)
+}
+
+pub fn count_fully_contained_assignement_in_pair_from_input(contents: &str) -> Result<u32, Error> {
+    parse_pairs(contents)?.count_pairs(Pair::has_contained_assignements, &CancelToken::default())
+}
+
+pub fn count_overlapping_assignement_in_pair(filename: &str) -> Result<u32, Error> {
+    let contents = input_dir::read(filename)?;
+    count_overlapping_assignement_in_pair_from_input(&contents)
+}
+
+pub fn count_overlapping_assignement_in_pair_from_input(contents: &str) -> Result<u32, Error> {
+    parse_pairs(contents)?.count_pairs(Pair::has_overlapping_assignemments, &CancelToken::default())
+}
+
+fn parse_line_into_pair(line: &str) -> Result<Pair, Error> {
+    let raw_assignements = line.trim().trim_end().split(",").collect::<Vec<&str>>();
+    if raw_assignements.len() != 2 {
+        return Err(Error::parse(line, "Expected two assignements from line"));
+    }
+
+    let pair = Pair {
+        a: Assignement::from_raw(raw_assignements[0])?,
+        b: Assignement::from_raw(raw_assignements[1])?,
+    };
+    return Ok(pair);
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Assignement {
+    start: u32,
+    end: u32,
+}
+
+impl Assignement {
+    fn from_raw(raw: &str) -> Result<Assignement, Error> {
+        let boundaries: Vec<_> = raw
+            .split("-")
+            .map(|x| {
+                return parse_value::<u32>(x, "a section number");
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if boundaries.len() != 2 {
+            return Err(Error::parse(
+                raw,
+                "Expected two buondaries in raw assignement",
+            ));
+        }
+
+        return Ok(Assignement {
+            start: boundaries[0],
+            end: boundaries[1],
+        });
+    }
+}
+
+#[derive(Debug)]
+struct Pair {
+    a: Assignement,
+    b: Assignement,
+}
+
+impl Pair {
+    fn has_contained_assignements(&self) -> bool {
+        let (first, second) = order_assignements(&self.a, &self.b);
+        return second.end <= first.end;
+    }
+
+    fn has_overlapping_assignemments(&self) -> bool {
+        let (first, second) = order_assignements(&self.a, &self.b);
+        return first.end >= second.start;
+    }
+}
+
+fn order_assignements<'a>(
+    a: &'a Assignement,
+    b: &'a Assignement,
+) -> (&'a Assignement, &'a Assignement) {
+    if a.start < b.start {
+        return (a, b);
+    }
+    if a.start == b.start {
+        if a.end < b.end {
+            return (b, a);
+        }
+        return (a, b);
+    }
+    return (b, a);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_has_right_answer() {
+        assert_eq!(
+            count_fully_contained_assignement_in_pair("inputs/2022/input-04-example.txt").unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn part_1_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-04.txt") {
+            return;
+        }
+        assert_eq!(
+            count_fully_contained_assignement_in_pair("inputs/2022/input-04.txt").unwrap(),
+            450
+        );
+    }
+
+    #[test]
+    fn example_part_2_has_right_answer() {
+        assert_eq!(
+            count_overlapping_assignement_in_pair("inputs/2022/input-04-example.txt").unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-04.txt") {
+            return;
+        }
+        assert_eq!(
+            count_overlapping_assignement_in_pair("inputs/2022/input-04.txt").unwrap(),
+            837
+        );
+    }
+}
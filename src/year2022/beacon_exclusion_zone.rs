@@ -0,0 +1,375 @@
+use std::{self, collections::HashSet};
+
+use regex::Regex;
+
+use crate::{
+    debug,
+    error::{parse_value, ErrorCollector},
+    input_dir, Answer, CancelToken, Error, Input, InputSet, Param, Part, Puzzle, Solver,
+};
+
+pub struct BeaconExclusionZone;
+
+impl Solver for BeaconExclusionZone {
+    fn name(&self) -> &'static str {
+        "beacon-exclusion-zone"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn module(&self) -> &'static str {
+        "beacon_exclusion_zone"
+    }
+
+    fn title(&self) -> &'static str {
+        "Beacon Exclusion Zone"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_sensors(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one sensor per line, e.g. `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "row",
+                description: "row where the covered positions are counted, 10 for the example and 2000000 for the real input",
+                parts: &[Part::Part1],
+                range: -1_000_000_000..=1_000_000_000,
+            },
+            Param {
+                name: "bound",
+                description: "largest coordinate of the distress beacon, 4000000",
+                parts: &[Part::Part2],
+                range: 0..=1_000_000_000,
+            },
+        ]
+    }
+}
+
+/// Sensors with the closest beacon they detect
+pub struct Sensors {
+    sensors: Vec<Sensor>,
+}
+
+impl Puzzle for Sensors {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        let row = input.params.get(
+            "row",
+            if input.input_set == InputSet::EXAMPLE {
+                10
+            } else {
+                2_000_000
+            },
+        )?;
+        Ok(self.covered_positions_in_row(row, &input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .distress_beacon_tuning_frequency(input.params.get("bound", 4_000_000)?, &input.cancel)?
+            .into())
+    }
+}
+
+pub fn parse_sensors(content: &str) -> Result<Sensors, Error> {
+    let mut sensors = vec![];
+    let mut errors = ErrorCollector::new();
+    for (index, line) in content.lines().enumerate() {
+        if let Some(sensor) =
+            errors.check(Sensor::try_from(line).map_err(|e| e.at_line(index, line)))?
+        {
+            sensors.push(sensor);
+        }
+    }
+    errors.finish()?;
+    Ok(Sensors { sensors })
+}
+
+pub fn find_number_of_covered_positions_in_row(
+    filename: &str,
+    target_y: isize,
+) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_number_of_covered_positions_in_row_from_input(&content, target_y)
+}
+
+pub fn find_number_of_covered_positions_in_row_from_input(
+    content: &str,
+    target_y: isize,
+) -> Result<usize, Error> {
+    parse_sensors(content)?.covered_positions_in_row(target_y, &CancelToken::default())
+}
+
+pub fn find_distress_beacon_tuning_frequency(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_distress_beacon_tuning_frequency_from_input(&content, 4_000_000)
+}
+
+pub fn find_distress_beacon_tuning_frequency_from_input(
+    content: &str,
+    search_bound: isize,
+) -> Result<usize, Error> {
+    parse_sensors(content)?.distress_beacon_tuning_frequency(search_bound, &CancelToken::default())
+}
+
+impl Sensors {
+    fn covered_positions_in_row(
+        &self,
+        target_y: isize,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        let mut min_x = isize::MAX;
+        let mut max_x = isize::MIN;
+        let mut occupied_beacons_x_positions = HashSet::new();
+        for sensor in &self.sensors {
+            let d: isize = sensor.closest_beacon_distance.try_into().map_err(|_| {
+                Error::invalid_state(format!(
+                    "Distance {} of sensor is too large",
+                    sensor.closest_beacon_distance
+                ))
+            })?;
+            let sensor_min_x = sensor.position.x - d;
+            let sensor_max_x = sensor.position.x + d;
+            if sensor_min_x < min_x {
+                min_x = sensor_min_x;
+            }
+            if sensor_max_x > max_x {
+                max_x = sensor_max_x;
+            }
+            let beacon_x = sensor.closest_beacon_position.x;
+            if sensor.closest_beacon_position.y == target_y {
+                occupied_beacons_x_positions.insert(beacon_x);
+            }
+        }
+        debug!("Min x: {min_x}, max x: {max_x}");
+
+        let mut covered_positions = 0;
+        for x in min_x..max_x + 1 {
+            cancel.check(|| {
+                format!(
+                    "{} of {} positions of the row checked",
+                    x - min_x,
+                    max_x - min_x + 1
+                )
+            })?;
+            if occupied_beacons_x_positions.contains(&x) {
+                continue;
+            }
+            let p = Point::new(x, target_y);
+
+            let is_position_covered = self.sensors.iter().any(|s| s.within_distance(&p));
+            if is_position_covered {
+                covered_positions += 1;
+            }
+        }
+        Ok(covered_positions)
+    }
+
+    fn distress_beacon_tuning_frequency(
+        &self,
+        search_bound: isize,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        let mut min_x = isize::MAX;
+        let mut max_x = isize::MIN;
+        let mut min_y = isize::MAX;
+        let mut max_y = isize::MIN;
+        for sensor in &self.sensors {
+            if sensor.position.x > max_x {
+                max_x = sensor.position.x;
+            }
+            if sensor.position.x < min_x {
+                min_x = sensor.position.x;
+            }
+            if sensor.position.y > max_y {
+                max_y = sensor.position.y;
+            }
+            if sensor.position.x < min_y {
+                min_y = sensor.position.y;
+            }
+        }
+
+        let mut sensors = self.sensors.iter().collect::<Vec<&Sensor>>();
+        sensors.sort_unstable_by(|a, b| b.position.x.cmp(&a.position.x));
+
+        min_x = std::cmp::max(0, min_x);
+        max_x = std::cmp::min(search_bound, max_x);
+        min_y = std::cmp::max(0, min_y);
+        max_y = std::cmp::min(search_bound, max_y);
+
+        debug!(
+            "Boundaries:
+        X: {min_x} to {max_x}
+        Y: {min_y} to {max_y}
+    "
+        );
+
+        let mut x = min_x;
+        let mut y = min_y;
+        while y <= max_y {
+            let p = Point::new(x, y);
+            let mut is_position_covered = false;
+            for sensor in &sensors {
+                if sensor.within_distance(&p) {
+                    let available_x_distance =
+                        sensor.closest_beacon_distance as isize - (p.y - sensor.position.y).abs();
+                    let next_x = sensor.position.x + available_x_distance;
+                    if next_x > max_x {
+                        x = min_x;
+                        y += 1;
+                        cancel.check(|| {
+                            format!("{} of {} rows scanned", y - min_y, max_y - min_y + 1)
+                        })?;
+                    } else if next_x == x {
+                        x += 1;
+                    } else {
+                        x = next_x;
+                    }
+                    is_position_covered = true;
+                    break;
+                }
+            }
+            if !is_position_covered {
+                let tuning_frequency = p.x * 4_000_000 + p.y;
+                debug!(
+                    "Found uncovered position:
+                X: {}
+                Y: {}
+                tuning frequency: {}
+            ",
+                    p.x, p.y, tuning_frequency
+                );
+                return Ok(tuning_frequency as usize);
+            }
+        }
+        Err(Error::no_solution(
+            "Unable to have found an uncovered position",
+        ))
+    }
+}
+
+struct Sensor {
+    position: Point,
+    closest_beacon_position: Point,
+    closest_beacon_distance: usize,
+}
+
+impl Sensor {
+    fn within_distance(&self, p: &Point) -> bool {
+        Point::distance(&self.position, p) <= self.closest_beacon_distance
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl Point {
+    fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    fn distance(p0: &Point, p1: &Point) -> usize {
+        return ((p0.x - p1.x).abs() + (p0.y - p1.y).abs()) as usize;
+    }
+}
+
+impl TryFrom<&str> for Sensor {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let pattern = Regex::new(
+            r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)",
+        )
+        .map_err(|e| Error::invalid_state(format!("Invalid sensor pattern: {}", e)))?;
+        let captures = pattern.captures(value).ok_or(Error::parse(value, "Unable to parse line into sensor, expected line of format `Sensor at x=<value>, y=<value>: closest beacon is at x=<value>, y=<value>`"))?;
+        let coordinate = |group: usize, name: &str| -> Result<isize, Error> {
+            let raw = captures.get(group).ok_or(Error::parse(
+                value,
+                format!("Missing the {} in the sensor line", name),
+            ))?;
+            parse_value::<isize>(raw.as_str(), &format!("a numerical value for the {}", name))
+                .map_err(|e| e.at_column(raw.start()))
+        };
+        let sensor_x = coordinate(1, "`x` position of sensor")?;
+        let sensor_y = coordinate(2, "`y` position of sensor")?;
+        let beacon_x = coordinate(3, "`x` position of beacon")?;
+        let beacon_y = coordinate(4, "`y` position of beacon")?;
+
+        //         println!("New sensor:
+        //         X: {sensor_x}
+        //         Y: {sensor_y}
+        // With beacon
+        //         X: {beacon_x}
+        //         Y: {beacon_y}
+        //         ");
+
+        let sensor_position = Point::new(sensor_x, sensor_y);
+        let beacon_position = Point::new(beacon_x, beacon_y);
+
+        let distance = Point::distance(&sensor_position, &beacon_position);
+
+        Ok(Sensor {
+            closest_beacon_distance: distance,
+            closest_beacon_position: beacon_position,
+            position: sensor_position,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_should_give_expected_result() {
+        assert_eq!(
+            find_number_of_covered_positions_in_row("inputs/2022/input-15-example.txt", 10)
+                .unwrap(),
+            26
+        );
+    }
+
+    #[test]
+    fn part_1_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-15.txt") {
+            return;
+        }
+        assert_eq!(
+            find_number_of_covered_positions_in_row("inputs/2022/input-15.txt", 2_000_000).unwrap(),
+            5716881
+        );
+    }
+
+    #[test]
+    fn example_part_2_should_give_expected_result() {
+        assert_eq!(
+            find_distress_beacon_tuning_frequency("inputs/2022/input-15-example.txt").unwrap(),
+            56000011
+        );
+    }
+
+    #[test]
+    fn part_2_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-15.txt") {
+            return;
+        }
+        assert_eq!(
+            find_distress_beacon_tuning_frequency("inputs/2022/input-15.txt").unwrap(),
+            10852583132904
+        );
+    }
+}
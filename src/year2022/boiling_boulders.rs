@@ -1,26 +1,82 @@
 use std;
 
-pub fn derive_surface_area(
-    filename: &str,
+use crate::{
+    error::{parse_value, ErrorCollector},
+    input_dir, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
+
+pub struct BoilingBoulders;
+
+impl Solver for BoilingBoulders {
+    fn name(&self) -> &'static str {
+        "boiling-boulders"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn module(&self) -> &'static str {
+        "boiling_boulders"
+    }
+
+    fn title(&self) -> &'static str {
+        "Boiling Boulders"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_lava_structure(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one cube per line as `x,y,z`, e.g. `2,2,2`"
+    }
+}
+
+pub fn derive_surface_area(filename: &str, filter_inner_air_pockets: bool) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    derive_surface_area_from_input(&content, filter_inner_air_pockets)
+}
+
+pub fn derive_surface_area_from_input(
+    content: &str,
     filter_inner_air_pockets: bool,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+) -> Result<usize, Error> {
+    parse_lava_structure(content)?.surface_area(filter_inner_air_pockets, &CancelToken::default())
+}
 
+pub fn parse_lava_structure(content: &str) -> Result<LavaStructure, Error> {
     let mut lava_structure = LavaStructure::new();
 
-    for line in content.lines() {
-        let p = Position::try_from(line)?;
+    let mut errors = ErrorCollector::new();
+    for (index, line) in content.lines().enumerate() {
+        let Some(p) = errors.check(Position::try_from(line).map_err(|e| e.at_line(index, line)))?
+        else {
+            continue;
+        };
         lava_structure.add_droplet(p);
     }
+    errors.finish()?;
+
+    Ok(lava_structure)
+}
 
-    if !filter_inner_air_pockets {
-        return Ok(lava_structure.max_surface_area);
+impl Puzzle for LavaStructure {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.surface_area(false, &input.cancel)?.into())
     }
 
-    Ok(lava_structure.max_surface_area - lava_structure.derive_inner_air_pockets_surface_area())
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.surface_area(true, &input.cancel)?.into())
+    }
 }
 
-struct LavaStructure {
+/// Scanned lava droplets with the surface they expose
+pub struct LavaStructure {
     max_surface_area: usize,
     droplet_positions: std::collections::HashSet<Position>,
     potential_water_positions: std::collections::HashSet<Position>,
@@ -62,6 +118,18 @@ impl LavaStructure {
         }
     }
 
+    fn surface_area(
+        &self,
+        filter_inner_air_pockets: bool,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        if !filter_inner_air_pockets {
+            return Ok(self.max_surface_area);
+        }
+
+        Ok(self.max_surface_area - self.derive_inner_air_pockets_surface_area(cancel)?)
+    }
+
     fn potential_water_positions(&self, p: &Position) -> Vec<Position> {
         p.around_positions()
             .into_iter()
@@ -69,12 +137,19 @@ impl LavaStructure {
             .collect()
     }
 
-    fn derive_inner_air_pockets_surface_area(&self) -> usize {
+    fn derive_inner_air_pockets_surface_area(&self, cancel: &CancelToken) -> Result<usize, Error> {
         let mut inner_surface_area = 0;
         let mut valid_water_positions = std::collections::HashSet::new();
         let mut invalid_water_positions = std::collections::HashSet::new();
 
-        for p in &self.potential_water_positions {
+        for (index, p) in self.potential_water_positions.iter().enumerate() {
+            cancel.check(|| {
+                format!(
+                    "{} of {} positions around the droplets checked",
+                    index,
+                    self.potential_water_positions.len()
+                )
+            })?;
             if valid_water_positions.contains(p) {
                 continue;
             }
@@ -107,7 +182,7 @@ impl LavaStructure {
             }
         }
 
-        inner_surface_area
+        Ok(inner_surface_area)
     }
 
     fn has_air_nearby(
@@ -248,15 +323,21 @@ impl Position {
 }
 
 impl TryFrom<&str> for Position {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let coordinates = value
             .split(",")
-            .map(|s| s.parse::<isize>().map_err(|e| e.into()))
-            .collect::<Result<Vec<isize>, Box<dyn std::error::Error>>>()?;
+            .map(|s| parse_value::<isize>(s, "a numerical coordinate"))
+            .collect::<Result<Vec<isize>, Error>>()?;
         if coordinates.len() != 3 {
-            return Err(format!("Invalid number of coordinates, expected 3, got {}. Value must be of the form `<x coordinate>,<y coordinate>,<z coordinate>`, got {}", coordinates.len(), value).into());
+            return Err(Error::parse(
+                value,
+                format!("Invalid number of coordinates, found {}", coordinates.len()),
+            )
+            .with_hint(
+                "3 coordinates of the form `<x coordinate>,<y coordinate>,<z coordinate>`",
+            ));
         }
 
         Ok(Position {
@@ -280,15 +361,18 @@ mod tests {
     #[test]
     fn example_part_1_should_give_expected_result() {
         assert_eq!(
-            derive_surface_area("inputs/input-18-example.txt", false).unwrap(),
+            derive_surface_area("inputs/2022/input-18-example.txt", false).unwrap(),
             64
         );
     }
 
     #[test]
     fn part_1_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-18.txt") {
+            return;
+        }
         assert_eq!(
-            derive_surface_area("inputs/input-18.txt", false).unwrap(),
+            derive_surface_area("inputs/2022/input-18.txt", false).unwrap(),
             4604
         );
     }
@@ -296,15 +380,18 @@ mod tests {
     #[test]
     fn example_part_2_should_give_expected_result() {
         assert_eq!(
-            derive_surface_area("inputs/input-18-example.txt", true).unwrap(),
+            derive_surface_area("inputs/2022/input-18-example.txt", true).unwrap(),
             58
         );
     }
 
     #[test]
     fn part_2_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-18.txt") {
+            return;
+        }
         assert_eq!(
-            derive_surface_area("inputs/input-18.txt", true).unwrap(),
+            derive_surface_area("inputs/2022/input-18.txt", true).unwrap(),
             2604
         );
     }
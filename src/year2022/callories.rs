@@ -0,0 +1,156 @@
+use crate::{
+    error::{parse_value, ErrorCollector},
+    input_dir, Answer, Error, Input, Puzzle, Solver,
+};
+
+pub struct Callories;
+
+impl Solver for Callories {
+    fn name(&self) -> &'static str {
+        "callories"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn module(&self) -> &'static str {
+        "callories"
+    }
+
+    fn title(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_elves(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one number of calories per line, the elves being separated by a blank line"
+    }
+}
+
+/// Total of the calories carried by each elf
+pub struct Elves {
+    callories: Vec<usize>,
+}
+
+impl Puzzle for Elves {
+    fn part_1(&self, _input: &Input) -> Result<Answer, Error> {
+        Ok(self.max_callories().into())
+    }
+
+    fn part_2(&self, _input: &Input) -> Result<Answer, Error> {
+        Ok(self.sum_of_maximums_callories(3).into())
+    }
+}
+
+impl Elves {
+    fn max_callories(&self) -> usize {
+        self.callories.iter().copied().max().unwrap_or(0)
+    }
+
+    fn sum_of_maximums_callories(&self, number_of_elves_to_consider: usize) -> usize {
+        let mut callories = self.callories.clone();
+        callories.sort_unstable_by(|a, b| b.cmp(a));
+        callories.iter().take(number_of_elves_to_consider).sum()
+    }
+}
+
+pub fn parse_elves(contents: &str) -> Result<Elves, Error> {
+    let mut callories = vec![];
+    let mut elf_sum = 0;
+    let mut errors = ErrorCollector::new();
+    for (index, line) in contents.lines().enumerate() {
+        let is_new_elf = line.is_empty();
+        if is_new_elf {
+            callories.push(elf_sum);
+            elf_sum = 0;
+        } else {
+            let Some(callory) = errors.check(
+                parse_value::<usize>(line, "a number of calories")
+                    .map_err(|e| e.at_line(index, line)),
+            )?
+            else {
+                continue;
+            };
+            elf_sum += callory;
+        }
+    }
+    callories.push(elf_sum);
+    errors.finish()?;
+
+    Ok(Elves { callories })
+}
+
+pub fn find_max_callories_on_single_elf(filename: &str) -> Result<usize, Error> {
+    let contents = input_dir::read(filename)?;
+    find_max_callories_on_single_elf_from_input(&contents)
+}
+
+pub fn find_max_callories_on_single_elf_from_input(contents: &str) -> Result<usize, Error> {
+    Ok(parse_elves(contents)?.max_callories())
+}
+
+pub fn find_sum_of_maximums_callories(
+    filename: &str,
+    number_of_elves_to_consider: usize,
+) -> Result<usize, Error> {
+    let contents = input_dir::read(filename)?;
+    find_sum_of_maximums_callories_from_input(&contents, number_of_elves_to_consider)
+}
+
+pub fn find_sum_of_maximums_callories_from_input(
+    contents: &str,
+    number_of_elves_to_consider: usize,
+) -> Result<usize, Error> {
+    Ok(parse_elves(contents)?.sum_of_maximums_callories(number_of_elves_to_consider))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_should_give_expected_maximum_callories_on_single_elf() {
+        assert_eq!(
+            find_max_callories_on_single_elf("inputs/2022/input-01-example.txt").unwrap(),
+            24000
+        );
+    }
+
+    #[test]
+    fn part_1_should_give_expected_maximum_callories_on_single_elf() {
+        if input_dir::skip_without_key("inputs/2022/input-01.txt") {
+            return;
+        }
+        assert_eq!(
+            find_max_callories_on_single_elf("inputs/2022/input-01.txt").unwrap(),
+            71471
+        );
+    }
+
+    #[test]
+    fn example_part_2_should_give_expected_sum_of_three_most_callories() {
+        assert_eq!(
+            find_sum_of_maximums_callories("inputs/2022/input-01-example.txt", 3).unwrap(),
+            45000
+        );
+    }
+
+    #[test]
+    fn part_2_should_give_expected_sum_of_three_most_callories() {
+        if input_dir::skip_without_key("inputs/2022/input-01.txt") {
+            return;
+        }
+        assert_eq!(
+            find_sum_of_maximums_callories("inputs/2022/input-01.txt", 3).unwrap(),
+            211189
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    error::ErrorCollector, input_dir, trace, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
+
+pub struct CathodRayTube;
+
+impl Solver for CathodRayTube {
+    fn name(&self) -> &'static str {
+        "cathod-ray-tube"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn module(&self) -> &'static str {
+        "cathod_ray_tube"
+    }
+
+    fn title(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_program(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one instruction per line, `noop` or `addx <number>`"
+    }
+}
+
+pub fn sum_signal_strengths(filename: &str) -> Result<isize, Error> {
+    let contents = input_dir::read(filename)?;
+    sum_signal_strengths_from_input(&contents)
+}
+
+pub fn sum_signal_strengths_from_input(contents: &str) -> Result<isize, Error> {
+    parse_program(contents)?.sum_signal_strengths(&CancelToken::default())
+}
+
+pub fn display_signal(filename: &str) -> Result<String, Error> {
+    let contents = input_dir::read(filename)?;
+    display_signal_from_input(&contents)
+}
+
+pub fn display_signal_from_input(contents: &str) -> Result<String, Error> {
+    parse_program(contents)?.display_signal(&CancelToken::default())
+}
+
+/// Instructions run by the CPU
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Puzzle for Program {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.sum_signal_strengths(&input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.display_signal(&input.cancel)?.into())
+    }
+}
+
+pub fn parse_program(contents: &str) -> Result<Program, Error> {
+    let mut instructions = vec![];
+    let mut errors = ErrorCollector::new();
+    for (index, line) in contents.lines().enumerate() {
+        let instruction = Instruction::try_from(line).map_err(|e| e.at_line(index, line));
+        if let Some(instruction) = errors.check(instruction)? {
+            instructions.push(instruction);
+        }
+    }
+    errors.finish()?;
+    Ok(Program { instructions })
+}
+
+impl Program {
+    fn sum_signal_strengths(&self, cancel: &CancelToken) -> Result<isize, Error> {
+        let mut cpu = CPU::new();
+
+        let mut next_cycle_of_interest: usize = 20;
+
+        let mut sum_signal_strength: isize = 0;
+
+        let mut instructions = self.instructions.iter();
+
+        loop {
+            cancel.check(|| format!("{} cycles run", cpu.cycle - 1))?;
+            if cpu.cycle == next_cycle_of_interest {
+                sum_signal_strength +=
+                    cpu.register * isize::try_from(next_cycle_of_interest).unwrap();
+                next_cycle_of_interest += 40;
+            }
+
+            if !cpu.is_executing() {
+                match instructions.next() {
+                    None => break,
+                    Some(instruction) => {
+                        cpu.begin_execution(instruction);
+                        trace!(
+                            "Cycle {}\nRegister value: {}\nStart execution of {:?}\n",
+                            cpu.cycle,
+                            cpu.register,
+                            instruction
+                        );
+                    }
+                }
+            } else {
+                trace!("Cycle {}\nRegister value: {}\n", cpu.cycle, cpu.register);
+            }
+
+            cpu.tick();
+        }
+
+        Ok(sum_signal_strength)
+    }
+
+    fn display_signal(&self, cancel: &CancelToken) -> Result<String, Error> {
+        let mut cpu = CPU::new();
+
+        let mut instructions = self.instructions.iter();
+
+        let mut result = "".to_owned();
+
+        loop {
+            cancel.check(|| format!("{} cycles run", cpu.cycle - 1))?;
+            if !cpu.is_executing() {
+                match instructions.next() {
+                    None => break,
+                    Some(instruction) => {
+                        cpu.begin_execution(instruction);
+                        trace!(
+                            "Cycle {}\nRegister value: {}\nStart execution of {:?}\n",
+                            cpu.cycle,
+                            cpu.register,
+                            instruction
+                        );
+                    }
+                }
+            } else {
+                trace!("Cycle {}\nRegister value: {}\n", cpu.cycle, cpu.register);
+            }
+
+            let cursor_position = (isize::try_from(cpu.cycle).unwrap() - 1) % 40;
+
+            trace!(
+                "Cursor position: {}\nLower boundary {}\nHigher boundary {} \n",
+                cursor_position,
+                cpu.register - 1,
+                cpu.register + 1
+            );
+            if cursor_position >= cpu.register - 1 && cursor_position <= cpu.register + 1 {
+                result += "#";
+            } else {
+                result += ".";
+            }
+
+            if cpu.cycle % 40 == 0 {
+                result += "\n";
+            }
+
+            cpu.tick();
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Noop,
+    Addx(isize),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "noop" => Ok(Instruction::Noop),
+            other => {
+                let trimmed_line = other.trim().trim_end();
+                let add_prefix = "addx ";
+                if !trimmed_line.starts_with(add_prefix) {
+                    return Err(Error::parse(other, "Unable to parse string into instruction, expected `noop` or `addx <value>`"));
+                }
+
+                let raw_value = trimmed_line.strip_prefix(add_prefix).unwrap_or_default();
+                match raw_value.parse::<isize>() {
+                    Err(_) => Err(Error::parse(raw_value, "Unable to parse string into instruction, expected a numeric value after the `addx ` part")),
+                    Ok(x) =>  Ok(Instruction::Addx(x))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct NextState {
+    cycle: usize,
+    register: isize,
+}
+
+#[derive(Debug)]
+struct CPU {
+    cycle: usize,
+    register: isize,
+    executing: Option<NextState>,
+}
+
+impl CPU {
+    fn is_executing(&self) -> bool {
+        return self.executing.is_some();
+    }
+
+    fn tick(&mut self) {
+        self.cycle += 1;
+        if let Some(next_state) = &self.executing {
+            if next_state.cycle == self.cycle {
+                self.register = next_state.register;
+                self.executing = None;
+            }
+        }
+    }
+
+    fn begin_execution(&mut self, instruction: &Instruction) {
+        let next_state = match instruction {
+            Instruction::Noop => NextState {
+                cycle: self.cycle + 1,
+                register: self.register,
+            },
+            Instruction::Addx(v) => NextState {
+                cycle: self.cycle + 2,
+                register: self.register + v,
+            },
+        };
+        self.executing = Some(next_state);
+    }
+}
+
+impl CPU {
+    fn new() -> Self {
+        CPU {
+            register: 1,
+            cycle: 1,
+            executing: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_should_give_expected_result() {
+        assert_eq!(
+            sum_signal_strengths("inputs/2022/input-10-example.txt").unwrap(),
+            13140
+        );
+    }
+
+    #[test]
+    fn part_1_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-10.txt") {
+            return;
+        }
+        assert_eq!(
+            sum_signal_strengths("inputs/2022/input-10.txt").unwrap(),
+            14860
+        );
+    }
+
+    #[test]
+    fn example_part_2_should_give_expected_result() {
+        assert_eq!(
+            display_signal("inputs/2022/input-10-example.txt").unwrap(),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....\n"
+                .to_owned()
+        )
+    }
+
+    #[test]
+    fn part_2_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-10.txt") {
+            return;
+        }
+        assert_eq!(
+            display_signal("inputs/2022/input-10.txt").unwrap(),
+            "###...##..####.####.#..#.#..#.###..#..#.
+#..#.#..#....#.#....#..#.#..#.#..#.#.#..
+#..#.#......#..###..####.#..#.#..#.##...
+###..#.##..#...#....#..#.#..#.###..#.#..
+#.#..#..#.#....#....#..#.#..#.#.#..#.#..
+#..#..###.####.####.#..#..##..#..#.#..#.\n"
+                .to_owned()
+        )
+    }
+
+    #[test]
+    fn malformed_instruction_is_located_in_the_input() {
+        match sum_signal_strengths_from_input("noop\naddx 3\naddx x1\n") {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e.line, Some(3));
+                assert_eq!(e.column, Some(6));
+                assert_eq!(e.text, "x1");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+}
@@ -0,0 +1,504 @@
+use std::{
+    self,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    debug,
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, Param, Part, Puzzle, Solver,
+};
+
+const TOTAL_DISK_SPACE: usize = 70_000_000;
+const SPACE_REQUIRED_FOR_UPDATE: usize = 30_000_000;
+
+pub struct DirectorySizes;
+
+impl Solver for DirectorySizes {
+    fn name(&self) -> &'static str {
+        "directory"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn module(&self) -> &'static str {
+        "directory"
+    }
+
+    fn title(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_disk_usage(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "a terminal session of `$ cd <dir>` and `$ ls` commands, `ls` listing e.g. `14848514 b.txt` or `dir a`"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "disk_space",
+                description: "total space of the disk, 70000000",
+                parts: &[Part::Part2],
+                range: 0..=i64::MAX,
+            },
+            Param {
+                name: "required_space",
+                description: "free space required by the update, 30000000",
+                parts: &[Part::Part2],
+                range: 0..=i64::MAX,
+            },
+        ]
+    }
+}
+
+/// Size of every directory of the file system, the sizes including the sub-directories
+pub struct DiskUsage {
+    root_path: String,
+    path_to_directory_size: HashMap<String, usize>,
+}
+
+impl Puzzle for DiskUsage {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.sum_of_small_directories(&input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .smallest_dir_to_delete_for_update(
+                input.params.get("disk_space", TOTAL_DISK_SPACE)?,
+                input
+                    .params
+                    .get("required_space", SPACE_REQUIRED_FOR_UPDATE)?,
+                &input.cancel,
+            )?
+            .into())
+    }
+}
+
+pub fn parse_disk_usage(content: &str) -> Result<DiskUsage, Error> {
+    let file_system = parse_input_to_file_system(content)?;
+
+    let path_to_directory_size = file_system.compute_directories_sizes()?;
+
+    Ok(DiskUsage {
+        root_path: file_system.root_path,
+        path_to_directory_size,
+    })
+}
+
+pub fn find_sum_of_small_diretories(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_sum_of_small_diretories_from_input(&content)
+}
+
+pub fn find_sum_of_small_diretories_from_input(content: &str) -> Result<usize, Error> {
+    parse_disk_usage(content)?.sum_of_small_directories(&CancelToken::default())
+}
+
+pub fn find_smallest_dir_to_delete_for_update(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_smallest_dir_to_delete_for_update_from_input(
+        &content,
+        TOTAL_DISK_SPACE,
+        SPACE_REQUIRED_FOR_UPDATE,
+    )
+}
+
+pub fn find_smallest_dir_to_delete_for_update_from_input(
+    content: &str,
+    total_disk_space: usize,
+    space_required_for_update: usize,
+) -> Result<usize, Error> {
+    parse_disk_usage(content)?.smallest_dir_to_delete_for_update(
+        total_disk_space,
+        space_required_for_update,
+        &CancelToken::default(),
+    )
+}
+
+impl DiskUsage {
+    fn sum_of_small_directories(&self, cancel: &CancelToken) -> Result<usize, Error> {
+        let mut sum = 0;
+        for (index, (dir_path, dir_size)) in self.path_to_directory_size.iter().enumerate() {
+            cancel.check(|| self.progress(index))?;
+            if *dir_size <= 100_000 {
+                trace!(
+                    "Found directory of small size, path: {:?}, size: {}",
+                    dir_path,
+                    dir_size
+                );
+                sum += dir_size;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn smallest_dir_to_delete_for_update(
+        &self,
+        total_disk_space: usize,
+        space_required_for_update: usize,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        let root_size =
+            self.path_to_directory_size
+                .get(&self.root_path)
+                .ok_or(Error::invalid_state(
+                    "Unable to get the size of the root repository",
+                ))?;
+
+        let remaining_space =
+            total_disk_space
+                .checked_sub(*root_size)
+                .ok_or(Error::invalid_state(format!(
+                    "The files take {} but the disk only has {}",
+                    root_size, total_disk_space
+                )))?;
+
+        let minimum_space_to_free = space_required_for_update.saturating_sub(remaining_space);
+
+        debug!("Minimmum space to free: {}", minimum_space_to_free);
+
+        let mut dir_sizes = self
+            .path_to_directory_size
+            .iter()
+            .map(|(_, dir_size)| dir_size)
+            .collect::<Vec<_>>();
+
+        dir_sizes.sort_unstable();
+
+        for (index, dir_size) in dir_sizes.into_iter().enumerate() {
+            cancel.check(|| self.progress(index))?;
+            if dir_size >= &minimum_space_to_free {
+                return Ok(*dir_size);
+            }
+        }
+
+        Err(Error::no_solution("Unable to find the magic directory"))
+    }
+
+    fn progress(&self, index: usize) -> String {
+        format!(
+            "{} of {} directories checked",
+            index,
+            self.path_to_directory_size.len()
+        )
+    }
+}
+
+fn build_child_path(current_path: &str, dir_name: &str) -> String {
+    current_path.to_string() + dir_name + "/"
+}
+
+fn build_previous_path(current_path: &str) -> String {
+    let mut path_items = current_path
+        .trim_end_matches("/")
+        .split("/")
+        .collect::<Vec<_>>();
+    path_items.pop();
+
+    path_items.join("/") + "/"
+}
+
+enum LsResult<'a> {
+    File { name: &'a str, size: usize },
+    Dir(&'a str),
+}
+
+impl<'a> LsResult<'a> {
+    fn try_from_line(line: &'a str) -> Result<Self, Error> {
+        let ls_elements = line.trim().trim_end().split(" ").collect::<Vec<&str>>();
+        if ls_elements.len() != 2 {
+            return Err(Error::parse(
+                line,
+                "Unexpected line after `ls`, expected a format such as `dir <DIR_NAME>` or `<FILE_SIZE> <FILENAME>`",
+            ));
+        }
+        match ls_elements[0] {
+            "dir" => {
+                let dir_name = ls_elements[1];
+                if dir_name.is_empty() {
+                    return Err(Error::parse(line, "Unexpected `dir` with empty name"));
+                }
+                return Ok(LsResult::Dir(dir_name));
+            }
+            other => {
+                let file_name = ls_elements[1];
+                if file_name.is_empty() {
+                    return Err(Error::parse(line, "Unexpected `file` with empty name"));
+                }
+                let file_size = parse_value::<usize>(other, "a file size")?;
+                return Ok(LsResult::File {
+                    name: file_name,
+                    size: file_size,
+                });
+            }
+        }
+    }
+}
+
+enum Cmd<'a> {
+    Cd(&'a str),
+    Ls,
+}
+
+impl<'a> Cmd<'a> {
+    fn try_from_line(line: &'a str) -> Result<Self, Error> {
+        let cmd_elements = line.trim().trim_end().split(" ").collect::<Vec<&str>>();
+        if cmd_elements.len() == 1 {
+            return Err(Error::parse(
+                line,
+                "Unexpected command line, expected a format such as `$ cd <ARGS>` or `$ ls`",
+            ));
+        }
+
+        let cmd_type = cmd_elements[1];
+        match cmd_type {
+            "ls" => {
+                return Ok(Cmd::Ls);
+            }
+            "cd" => {
+                if cmd_elements.len() != 3 {
+                    return Err(Error::parse(
+                        line,
+                        "Unexpected `cd` command format, expected a format `$ cd <NAME>`",
+                    ));
+                }
+                let target_dir_name = cmd_elements[2];
+                return Ok(Cmd::Cd(target_dir_name));
+            }
+            other => {
+                return Err(Error::parse(
+                    other,
+                    "Unexpected command, expected `ls` or `cd`",
+                ));
+            }
+        }
+    }
+}
+
+fn parse_input_to_file_system(content: &str) -> Result<FileSystem, Error> {
+    let mut file_system = FileSystem::new("/");
+    let mut current_path = file_system.root_path.to_string();
+    let mut errors = ErrorCollector::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let is_command = line.starts_with("$");
+        if is_command {
+            let Some(cmd) =
+                errors.check(Cmd::try_from_line(line).map_err(|e| e.at_line(index, line)))?
+            else {
+                continue;
+            };
+
+            match cmd {
+                Cmd::Ls => continue,
+                Cmd::Cd(target_dir_name) => match target_dir_name {
+                    ".." => {
+                        trace!("Going back to parent of path: {}", current_path);
+
+                        current_path = build_previous_path(&current_path);
+                    }
+                    "/" => {
+                        trace!("Going back to root: {}", current_path);
+
+                        current_path = "/".to_string();
+                    }
+                    dir_name => {
+                        if !file_system.has_child(&current_path, dir_name) {
+                            file_system.insert_dir_with_parent(&current_path, dir_name)?;
+                        }
+
+                        let dir_path = build_child_path(&current_path, dir_name);
+
+                        trace!("Going from {} to: {}", current_path, dir_path);
+
+                        current_path = dir_path;
+                    }
+                },
+            };
+        } else {
+            let Some(ls_result) =
+                errors.check(LsResult::try_from_line(line).map_err(|e| e.at_line(index, line)))?
+            else {
+                continue;
+            };
+
+            match ls_result {
+                LsResult::Dir(dir_name) => {
+                    if !file_system.has_child(&current_path, dir_name) {
+                        file_system.insert_dir_with_parent(&current_path, dir_name)?;
+                    }
+                }
+                LsResult::File { name, size } => {
+                    file_system.add_file(&current_path, name, size)?;
+                }
+            }
+        }
+    }
+    errors.finish()?;
+    return Ok(file_system);
+}
+
+struct FileSystem {
+    root_path: String,
+    path_to_directory: HashMap<String, Directory>,
+}
+
+impl FileSystem {
+    fn new(root_path: &str) -> Self {
+        let mut path_to_directory = HashMap::new();
+
+        let root_dir = Directory::new();
+        path_to_directory.insert(root_path.to_string(), root_dir);
+
+        FileSystem {
+            root_path: root_path.to_string(),
+            path_to_directory,
+        }
+    }
+
+    fn has_child(&self, parent_path: &str, dir_name: &str) -> bool {
+        let child_path: String = build_child_path(parent_path, dir_name);
+        self.path_to_directory.contains_key(&child_path)
+    }
+
+    fn insert_dir_with_parent(
+        &mut self,
+        parent_path: &str,
+        dir_name: &str,
+    ) -> Result<String, Error> {
+        let parent_dir =
+            self.path_to_directory
+                .get_mut(parent_path)
+                .ok_or(Error::invalid_state(format!(
+                    "Unexpected not found repository with path {} in list",
+                    parent_path
+                )))?;
+        parent_dir.children_dir_names.insert(dir_name.to_string());
+
+        let child_path: String = build_child_path(parent_path, dir_name);
+
+        self.path_to_directory
+            .insert(child_path.clone(), Directory::new());
+
+        Ok(child_path)
+    }
+
+    fn add_file(&mut self, dir_path: &str, file_name: &str, file_size: usize) -> Result<(), Error> {
+        let dir = self
+            .path_to_directory
+            .get_mut(dir_path)
+            .ok_or(Error::invalid_state(format!(
+                "Unexpected not found repository with path {} in list",
+                dir_path
+            )))?;
+        dir.files.insert(file_name.to_string(), file_size);
+
+        Ok(())
+    }
+
+    fn compute_directories_sizes(&self) -> Result<HashMap<String, usize>, Error> {
+        let mut path_to_directory_size = HashMap::new();
+        self.compute_directory_size(&self.root_path, &mut path_to_directory_size)?;
+        Ok(path_to_directory_size)
+    }
+
+    fn compute_directory_size(
+        &self,
+        dir_path: &str,
+        map: &mut HashMap<String, usize>,
+    ) -> Result<usize, Error> {
+        let directory = self
+            .path_to_directory
+            .get(dir_path)
+            .ok_or(Error::invalid_state(format!(
+                "Unable to find directory of path {:?}",
+                dir_path
+            )))?;
+
+        let mut files_size = 0;
+        for (_, file_size) in &directory.files {
+            files_size += file_size;
+        }
+
+        let mut children_directories_size = 0;
+        for child_dir_name in &directory.children_dir_names {
+            let child_dir_path = build_child_path(dir_path, child_dir_name);
+            children_directories_size += self.compute_directory_size(&child_dir_path, map)?;
+        }
+
+        let size = files_size + children_directories_size;
+
+        map.insert(dir_path.to_string(), size);
+
+        return Ok(size);
+    }
+}
+
+#[derive(Debug)]
+struct Directory {
+    files: HashMap<String, usize>,
+    children_dir_names: HashSet<String>,
+}
+
+impl Directory {
+    fn new() -> Self {
+        Self {
+            files: HashMap::new(),
+            children_dir_names: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_has_right_answer() {
+        assert_eq!(
+            find_sum_of_small_diretories("inputs/2022/input-07-example.txt").unwrap(),
+            95437
+        );
+    }
+
+    #[test]
+    fn part_1_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-07.txt") {
+            return;
+        }
+        assert_eq!(
+            find_sum_of_small_diretories("inputs/2022/input-07.txt").unwrap(),
+            1449447
+        );
+    }
+
+    #[test]
+    fn example_part_2_has_right_answer() {
+        assert_eq!(
+            find_smallest_dir_to_delete_for_update("inputs/2022/input-07-example.txt").unwrap(),
+            24933642
+        );
+    }
+
+    #[test]
+    fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-07.txt") {
+            return;
+        }
+        assert_eq!(
+            find_smallest_dir_to_delete_for_update("inputs/2022/input-07.txt").unwrap(),
+            8679207
+        );
+    }
+}
@@ -1,103 +1,201 @@
 use std::{self, cmp::Ordering};
 
-pub fn sum_over_right_pair_indices(filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+use crate::{
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
 
-    let mut sum = 0;
-    let mut pair_index = 1;
+pub struct DistressSignal;
+
+impl Solver for DistressSignal {
+    fn name(&self) -> &'static str {
+        "distress-signal"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn module(&self) -> &'static str {
+        "distress_signal"
+    }
+
+    fn title(&self) -> &'static str {
+        "Distress Signal"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["distress-signals"]
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_packet_pairs(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "pairs of packets separated by a blank line, a packet being a list of numbers and lists, e.g. `[1,[2,3]]`"
+    }
+}
+
+/// Received packets, grouped by pair
+pub struct PacketPairs {
+    pairs: Vec<Vec<Packet>>,
+}
+
+impl Puzzle for PacketPairs {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.sum_over_right_pair_indices(&input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.decoder_key(&input.cancel)?.into())
+    }
+}
+
+pub fn parse_packet_pairs(content: &str) -> Result<PacketPairs, Error> {
+    let mut pairs = vec![];
+    let mut line_index = 0;
+    let mut errors = ErrorCollector::new();
 
     for raw_pair in content.split("\n\n") {
         let mut packets = vec![];
         for side_line in raw_pair.lines() {
-            if packets.len() > 2 {
-                return Err(format!(
-                    "Invalid pair input, expected at most two lines, got at least three"
-                )
-                .into());
+            if packets.len() >= 2 {
+                errors.report(
+                    Error::parse(side_line, "Invalid pair input, found a third line")
+                        .at_line(line_index, side_line)
+                        .with_hint("at most two lines per pair"),
+                )?;
+                line_index += 1;
+                continue;
             }
 
-            println!("Line {side_line}");
-            let packet = Packet::try_from(side_line)?;
+            trace!("Line {side_line}");
+            let packet = Packet::try_from(side_line).map_err(|e| e.at_line(line_index, side_line));
+            line_index += 1;
+            let Some(packet) = errors.check(packet)? else {
+                continue;
+            };
 
             packets.push(packet);
+        }
+        pairs.push(packets);
+        // Skip the blank line separating the pairs
+        line_index += 1;
+    }
+    errors.finish()?;
 
-            if packets.len() == 2 {
-                let left_side = &packets[0];
-                let right_side = &packets[1];
-
-                let order = left_side.cmp(right_side);
+    Ok(PacketPairs { pairs })
+}
 
-                if order == Ordering::Greater {
-                    sum += pair_index;
-                }
+pub fn sum_over_right_pair_indices(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    sum_over_right_pair_indices_from_input(&content)
+}
 
-                println!(
-                    "Got both sides:
-    Index {pair_index}
-    Order good: {}
-                ",
-                    order == Ordering::Greater
-                );
+pub fn sum_over_right_pair_indices_from_input(content: &str) -> Result<usize, Error> {
+    parse_packet_pairs(content)?.sum_over_right_pair_indices(&CancelToken::default())
+}
 
-                pair_index += 1;
-            }
-        }
-    }
+pub fn find_decoder_key(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_decoder_key_from_input(&content)
+}
 
-    Ok(sum)
+pub fn find_decoder_key_from_input(content: &str) -> Result<usize, Error> {
+    parse_packet_pairs(content)?.decoder_key(&CancelToken::default())
 }
 
-pub fn find_decoder_key(filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+impl PacketPairs {
+    fn sum_over_right_pair_indices(&self, cancel: &CancelToken) -> Result<usize, Error> {
+        let mut sum = 0;
+        let complete_pairs = self.pairs.iter().filter(|packets| packets.len() == 2);
+        for (index, packets) in complete_pairs.enumerate() {
+            cancel.check(|| format!("{} of {} pairs compared", index, self.pairs.len()))?;
+            let pair_index = index + 1;
+            let left_side = &packets[0];
+            let right_side = &packets[1];
 
-    let mut packets = vec![];
+            let order = left_side.cmp(right_side);
 
-    for raw_pair in content.split("\n\n") {
-        for packet_line in raw_pair.lines() {
-            packets.push(Packet::try_from(packet_line)?);
+            if order == Ordering::Greater {
+                sum += pair_index;
+            }
+
+            trace!(
+                "Got both sides:
+    Index {pair_index}
+    Order good: {}
+                ",
+                order == Ordering::Greater
+            );
         }
+
+        Ok(sum)
     }
 
-    // Add divider packets
-    let first_divider_packet = Packet {
-        items: vec![Item::List(vec![Item::Value(2)])],
-    };
-    let second_divider_packet = Packet {
-        items: vec![Item::List(vec![Item::Value(6)])],
-    };
-
-    packets.push(first_divider_packet.clone());
-    packets.push(second_divider_packet.clone());
-
-    packets.sort_unstable_by(|a, b| b.cmp(a));
-
-    let mut first_divider_packet_index = None;
-    let mut second_divider_packet_index = None;
-    let mut index = 1;
-    for packet in packets {
-        if packet.cmp(&first_divider_packet) == Ordering::Equal {
-            if first_divider_packet_index.is_some() {
-                return Err("Already found first divider packet :(".into());
-            } else {
-                first_divider_packet_index = Some(index);
+    fn decoder_key(&self, cancel: &CancelToken) -> Result<usize, Error> {
+        let mut packets = self.pairs.concat();
+
+        // Add divider packets
+        let first_divider_packet = Packet {
+            items: vec![Item::List(vec![Item::Value(2)])],
+        };
+        let second_divider_packet = Packet {
+            items: vec![Item::List(vec![Item::Value(6)])],
+        };
+
+        packets.push(first_divider_packet.clone());
+        packets.push(second_divider_packet.clone());
+
+        packets.sort_unstable_by(|a, b| b.cmp(a));
+
+        let packet_count = packets.len();
+        let mut first_divider_packet_index = None;
+        let mut second_divider_packet_index = None;
+        let mut index = 1;
+        for packet in packets {
+            cancel.check(|| {
+                format!(
+                    "{} of {} sorted packets searched for the dividers",
+                    index - 1,
+                    packet_count
+                )
+            })?;
+            if packet.cmp(&first_divider_packet) == Ordering::Equal {
+                if first_divider_packet_index.is_some() {
+                    return Err(Error::invalid_state(
+                        "Already found first divider packet :(",
+                    ));
+                } else {
+                    first_divider_packet_index = Some(index);
+                }
             }
-        }
-        if packet.cmp(&second_divider_packet) == Ordering::Equal {
-            if second_divider_packet_index.is_some() {
-                return Err("Already found second divider packet :(".into());
-            } else {
-                second_divider_packet_index = Some(index);
+            if packet.cmp(&second_divider_packet) == Ordering::Equal {
+                if second_divider_packet_index.is_some() {
+                    return Err(Error::invalid_state(
+                        "Already found second divider packet :(",
+                    ));
+                } else {
+                    second_divider_packet_index = Some(index);
+                }
             }
-        }
 
-        if first_divider_packet_index.is_some() && second_divider_packet_index.is_some() {
-            return Ok(first_divider_packet_index.unwrap() * second_divider_packet_index.unwrap());
+            if first_divider_packet_index.is_some() && second_divider_packet_index.is_some() {
+                return Ok(
+                    first_divider_packet_index.unwrap() * second_divider_packet_index.unwrap()
+                );
+            }
+
+            index += 1;
         }
 
-        index += 1;
+        return Err(Error::no_solution("Unable to have found divider packets"));
     }
-
-    return Err("Unable to have found divider packets".into());
 }
 
 #[derive(Debug, Clone)]
@@ -135,15 +233,14 @@ impl Packet {
 }
 
 impl TryFrom<&str> for Packet {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if !value.starts_with("[") || !value.ends_with("]") {
-            return Err(format!(
-                "Invalid packet string, expected to have format `[...]`, got {}",
-                value
-            )
-            .into());
+            return Err(Error::parse(
+                value,
+                "Invalid packet string, expected to have format `[...]`",
+            ));
         }
 
         let stripped_value = value.strip_prefix("[").and_then(|s| s.strip_suffix("]"));
@@ -158,7 +255,7 @@ impl TryFrom<&str> for Packet {
     }
 }
 
-fn parse_items(value: &str) -> Result<Vec<Item>, Box<dyn std::error::Error>> {
+fn parse_items(value: &str) -> Result<Vec<Item>, Error> {
     let mut items: Vec<Item> = vec![];
 
     let mut item_start_index = 0;
@@ -274,7 +371,7 @@ impl Item {
 }
 
 impl TryFrom<&str> for Item {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         // println!("Building item from {value}");
@@ -282,7 +379,7 @@ impl TryFrom<&str> for Item {
         let is_nested = value.starts_with("[") && value.ends_with("]");
 
         if !is_nested {
-            let v = value.parse::<usize>()?;
+            let v = parse_value::<usize>(value, "a packet value or a list")?;
             return Ok(Item::Value(v));
         }
 
@@ -303,15 +400,18 @@ mod tests {
     #[test]
     fn example_part_1_has_right_answer() {
         assert_eq!(
-            sum_over_right_pair_indices("inputs/input-13-example.txt").unwrap(),
+            sum_over_right_pair_indices("inputs/2022/input-13-example.txt").unwrap(),
             13
         );
     }
 
     #[test]
     fn part_1_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-13.txt") {
+            return;
+        }
         assert_eq!(
-            sum_over_right_pair_indices("inputs/input-13.txt").unwrap(),
+            sum_over_right_pair_indices("inputs/2022/input-13.txt").unwrap(),
             5682
         );
     }
@@ -319,13 +419,16 @@ mod tests {
     #[test]
     fn example_part_2_has_right_answer() {
         assert_eq!(
-            find_decoder_key("inputs/input-13-example.txt").unwrap(),
+            find_decoder_key("inputs/2022/input-13-example.txt").unwrap(),
             140
         );
     }
 
     #[test]
     fn part_2_has_right_answer() {
-        assert_eq!(find_decoder_key("inputs/input-13.txt").unwrap(), 20304);
+        if input_dir::skip_without_key("inputs/2022/input-13.txt") {
+            return;
+        }
+        assert_eq!(find_decoder_key("inputs/2022/input-13.txt").unwrap(), 20304);
     }
 }
@@ -0,0 +1,368 @@
+use std::{
+    fmt::{self},
+    str, vec,
+};
+
+use crate::{
+    debug,
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
+
+pub struct ElfCrates;
+
+impl Solver for ElfCrates {
+    fn name(&self) -> &'static str {
+        "elf-crates"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn module(&self) -> &'static str {
+        "elf_crates"
+    }
+
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_rearrangement(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "a drawing of the stacks of crates, a blank line, then one move per line, e.g. `move 1 from 2 to 1`"
+    }
+}
+
+/// Initial stacks of crates and the moves applied to them
+pub struct Rearrangement {
+    crates_setup: CratesSetup,
+    orders: Vec<Order>,
+}
+
+impl Puzzle for Rearrangement {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.top_crates_after_moves(true, &input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.top_crates_after_moves(false, &input.cancel)?.into())
+    }
+}
+
+pub fn parse_rearrangement(contents: &str) -> Result<Rearrangement, Error> {
+    let index = contents.find("\n\n").ok_or(Error::parse(
+        "",
+        "Unable to find double line break between the crates and the orders",
+    ))?;
+
+    let (crates_config, orders_config) = contents.split_at(index);
+
+    debug!("crates_confi: \n{}\n", crates_config);
+
+    let crates_setup = parse_crates_setup(crates_config)?;
+
+    debug!("crates setup: {}", crates_setup);
+
+    // The orders start on the last line of the crates configuration, right after it
+    let orders = parse_orders(
+        orders_config,
+        crates_config.lines().count().saturating_sub(1),
+    )?;
+
+    Ok(Rearrangement {
+        crates_setup,
+        orders,
+    })
+}
+
+pub fn move_crates(
+    filename: &str,
+    should_move_crate_one_at_the_time: bool,
+) -> Result<String, Error> {
+    let contents = input_dir::read(filename)?;
+    move_crates_from_input(&contents, should_move_crate_one_at_the_time)
+}
+
+pub fn move_crates_from_input(
+    contents: &str,
+    should_move_crate_one_at_the_time: bool,
+) -> Result<String, Error> {
+    parse_rearrangement(contents)?
+        .top_crates_after_moves(should_move_crate_one_at_the_time, &CancelToken::default())
+}
+
+impl Rearrangement {
+    fn top_crates_after_moves(
+        &self,
+        should_move_crate_one_at_the_time: bool,
+        cancel: &CancelToken,
+    ) -> Result<String, Error> {
+        let mut crates_setup = self.crates_setup.clone();
+
+        for (index, order) in self.orders.iter().enumerate() {
+            cancel.check(|| format!("{} of {} moves applied", index, self.orders.len()))?;
+            if should_move_crate_one_at_the_time {
+                crates_setup.apply_order_one_crate_at_the_time(order)?;
+            } else {
+                crates_setup.apply_order_multiple_crates_at_the_time(order)?;
+            }
+
+            trace!("Order: {}", order);
+            trace!("crates setup: {}", crates_setup);
+        }
+
+        let last_elements: Result<Vec<_>, _> = crates_setup
+            .setup
+            .iter()
+            .map(|c| {
+                c.last()
+                    .ok_or(Error::invalid_state("Oh no, a stack of crates is empty"))
+            })
+            .collect();
+
+        let mut concat = "".to_string();
+        for el in last_elements? {
+            let unwrapped_element = el
+                .get(1..2)
+                .ok_or(Error::parse(el, "Unable to extract element in bracket"))?;
+            concat.push_str(unwrapped_element);
+        }
+
+        return Ok(concat);
+    }
+}
+
+#[derive(Clone)]
+struct CratesSetup {
+    setup: Vec<Vec<String>>,
+}
+
+impl CratesSetup {
+    fn apply_order_one_crate_at_the_time(&mut self, order: &Order) -> Result<(), Error> {
+        for _ in 0..order.quantity {
+            let moved_crate = self.setup[order.from]
+                .last()
+                .ok_or(Error::invalid_state(format!(
+                    "No last element found in stack {}",
+                    order.from + 1
+                )))?
+                .to_string();
+            self.setup[order.to].push(moved_crate);
+            self.setup[order.from].pop();
+        }
+        return Ok(());
+    }
+
+    fn apply_order_multiple_crates_at_the_time(&mut self, order: &Order) -> Result<(), Error> {
+        let from_column_length = self.setup[order.from].len();
+        let mut moved_crates: Vec<_> = self.setup[order.from]
+            .drain(from_column_length - order.quantity..)
+            .collect();
+        if moved_crates.len() != order.quantity {
+            return Err(Error::invalid_state(format!(
+                "Unexpected number of moved crates, expected {}, got {}",
+                order.quantity,
+                moved_crates.len()
+            )));
+        }
+
+        self.setup[order.to].append(&mut moved_crates);
+        return Ok(());
+    }
+}
+
+impl fmt::Display for CratesSetup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut displayed_string_vec: Vec<String> = vec![];
+        let mut first_line = "".to_string();
+        for i in 0..self.setup.len() {
+            first_line.push_str(&format!(" {} ", i + 1));
+            first_line.push_str(" ");
+        }
+
+        displayed_string_vec.push(first_line);
+
+        let highest_vec = self.setup.iter().map(|c| c.len()).max();
+
+        match highest_vec {
+            Some(highest_vec_size) => {
+                for i in 0..highest_vec_size {
+                    let mut displayed_line = "".to_string();
+                    for j in 0..self.setup.len() {
+                        if i >= self.setup[j].len() {
+                            displayed_line.push_str("   ");
+                        } else {
+                            displayed_line.push_str(&self.setup[j][i]);
+                        }
+                        displayed_line.push_str(" ");
+                    }
+                    displayed_string_vec.push(displayed_line);
+                }
+
+                displayed_string_vec.reverse();
+
+                return write!(f, "\n{}\n", displayed_string_vec.join("\n"));
+            }
+            None => {
+                return write!(f, "An issue occurred while displaying the Crates Setup!\n");
+            }
+        }
+    }
+}
+
+fn parse_crates_setup(crates_config: &str) -> Result<CratesSetup, Error> {
+    let mut crates_setup: Vec<Vec<String>> = vec![];
+
+    let mut is_first_line = true;
+
+    let mut number_of_column: usize = 0;
+
+    let lines = crates_config.lines().collect::<Vec<&str>>();
+    for (index, line) in lines.iter().enumerate().rev() {
+        if is_first_line {
+            let last_column = line.trim().trim_end().split_whitespace().last().ok_or(
+                Error::parse(
+                    line,
+                    "Unable to find last element of column configuration line",
+                )
+                .at_line(index, line),
+            )?;
+            number_of_column = parse_value::<usize>(last_column, "a column number")
+                .map_err(|e| e.at_line(index, line))?;
+            let mut i = 0;
+            while i < number_of_column {
+                crates_setup.push(vec![]);
+                i += 1;
+            }
+            is_first_line = false;
+        } else {
+            let mut i: usize = 0;
+            while i < number_of_column {
+                let part = line.chars().skip(4 * i).take(3).collect::<String>();
+                let trimed_part = part.trim().trim_end();
+                if !trimed_part.is_empty() {
+                    crates_setup[i].push(trimed_part.to_string());
+                }
+                i += 1;
+            }
+        }
+    }
+
+    return Ok(CratesSetup {
+        setup: crates_setup,
+    });
+}
+
+#[derive(Debug)]
+struct Order {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(
+            f,
+            "Move {} from {} to {}\n",
+            self.quantity,
+            self.from + 1,
+            self.to + 1
+        );
+    }
+}
+
+fn parse_orders(orders_config: &str, first_line_index: usize) -> Result<Vec<Order>, Error> {
+    let mut orders: Vec<Order> = vec![];
+    let mut errors = ErrorCollector::new();
+
+    for (index, line) in orders_config.lines().enumerate() {
+        let index = first_line_index + index;
+        let trimed_line = line.trim().trim_end();
+        if trimed_line.is_empty() {
+            continue;
+        }
+        let elements: Vec<_> = trimed_line.split(" ").collect();
+        if elements.len() != 6 {
+            errors.report(
+                Error::parse(line, format!("Found {} elements in line", elements.len()))
+                    .at_line(index, line)
+                    .with_hint("6 elements in line, `move <count> from <stack> to <stack>`"),
+            )?;
+            continue;
+        }
+        let parse_element = |i: usize, expected: &str| {
+            let column = line.len() - line.trim_start().len()
+                + elements[..i].iter().map(|e| e.len() + 1).sum::<usize>();
+            parse_value::<usize>(elements[i], expected)
+                .map_err(|e| e.at_column(column).at_line(index, line))
+        };
+        let (Some(quantity), Some(from), Some(to)) = (
+            errors.check(parse_element(1, "a quantity of crates"))?,
+            errors.check(parse_element(3, "a stack number"))?,
+            errors.check(parse_element(5, "a stack number"))?,
+        ) else {
+            continue;
+        };
+
+        orders.push(Order {
+            quantity: quantity,
+            from: from - 1,
+            to: to - 1,
+        });
+    }
+    errors.finish()?;
+
+    return Ok(orders);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_has_right_answer() {
+        assert_eq!(
+            move_crates("inputs/2022/input-05-example.txt", true).unwrap(),
+            "CMZ"
+        );
+    }
+
+    #[test]
+    fn part_1_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-05.txt") {
+            return;
+        }
+        assert_eq!(
+            move_crates("inputs/2022/input-05.txt", true).unwrap(),
+            "SHMSDGZVC"
+        );
+    }
+
+    #[test]
+    fn example_part_2_has_right_answer() {
+        assert_eq!(
+            move_crates("inputs/2022/input-05-example.txt", false).unwrap(),
+            "MCD"
+        );
+    }
+
+    #[test]
+    fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-05.txt") {
+            return;
+        }
+        assert_eq!(
+            move_crates("inputs/2022/input-05.txt", false).unwrap(),
+            "VRZGHDFBQ"
+        );
+    }
+}
@@ -3,10 +3,53 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
-    let hill_climb = HillClimb::try_from(content.as_str())?;
+use crate::{
+    debug, error::ErrorCollector, input_dir, trace, Answer, CancelToken, Error, Input, Puzzle,
+    Solver,
+};
+
+pub struct HillClimbing;
+
+impl Solver for HillClimbing {
+    fn name(&self) -> &'static str {
+        "hill-climbing"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn module(&self) -> &'static str {
+        "hill_climbing"
+    }
+
+    fn title(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(HillClimb::try_from(input.contents.as_str())?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "a grid of lowercase heights with one start `S` and one end `E`"
+    }
+}
+
+pub fn find_shortest_path(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_shortest_path_from_input(&content)
+}
+
+pub fn find_shortest_path_from_input(content: &str) -> Result<usize, Error> {
+    shortest_path(&HillClimb::try_from(content)?, &CancelToken::default())
+}
 
+fn shortest_path(hill_climb: &HillClimb, cancel: &CancelToken) -> Result<usize, Error> {
     let mut paths: HashMap<usize, HillPath> = HashMap::new();
 
     let mut global_path_index = 0;
@@ -16,11 +59,11 @@ pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::E
         HillPath::new(hill_climb.starting_position),
     );
 
-    println!(
+    debug!(
         "Start: {}",
         hill_climb.hill[hill_climb.starting_position.0][hill_climb.starting_position.1]
     );
-    println!(
+    debug!(
         "Target: {}",
         hill_climb.hill[hill_climb.target_position.0][hill_climb.target_position.1]
     );
@@ -30,16 +73,25 @@ pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::E
 
     let mut iteration = 0;
     loop {
+        cancel.check(|| {
+            format!(
+                "{} steps explored, {} positions visited",
+                iteration,
+                visited_indices.len()
+            )
+        })?;
         iteration += 1;
 
         let mut new_paths: Vec<HillPath> = vec![];
 
-        println!("Iteration [{iteration}] - I iterate with {} paths", {
+        trace!("Iteration [{iteration}] - I iterate with {} paths", {
             paths.len()
         });
 
         if paths.len() == 0 {
-            return Err("All created paths have been stopped without finding a solution".into());
+            return Err(Error::no_solution(
+                "All created paths have been stopped without finding a solution",
+            ));
         }
 
         let mut path_indices_to_remove = vec![];
@@ -48,7 +100,7 @@ pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::E
             // println!("I iterate with path #{index}");
 
             let possibilities = path
-                .derive_possibilities(&hill_climb)
+                .derive_possibilities(hill_climb)
                 .into_iter()
                 .filter(|p| !visited_indices.contains(p))
                 .collect::<Vec<(usize, usize)>>();
@@ -61,9 +113,9 @@ pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::E
                 }
                 1 => {
                     visited_indices.insert(possibilities[0]);
-                    path.visit(possibilities[0], &hill_climb);
-                    if path.has_reached_target(&hill_climb) {
-                        println!("Path {index} has reached target!");
+                    path.visit(possibilities[0], hill_climb);
+                    if path.has_reached_target(hill_climb) {
+                        debug!("Path {index} has reached target!");
                         return Ok(path.iteration);
                     }
                 }
@@ -71,28 +123,28 @@ pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::E
                     for i in 1..l {
                         visited_indices.insert(possibilities[i]);
                         let mut new_path = path.clone();
-                        new_path.visit(possibilities[i], &hill_climb);
-                        if new_path.has_reached_target(&hill_climb) {
-                            println!("Path {index} has reached target!");
+                        new_path.visit(possibilities[i], hill_climb);
+                        if new_path.has_reached_target(hill_climb) {
+                            debug!("Path {index} has reached target!");
                             return Ok(new_path.iteration);
                         }
                         new_paths.push(new_path);
                     }
                     visited_indices.insert(possibilities[0]);
-                    path.visit(possibilities[0], &hill_climb);
-                    if path.has_reached_target(&hill_climb) {
-                        println!("Path {index} has reached target!");
+                    path.visit(possibilities[0], hill_climb);
+                    if path.has_reached_target(hill_climb) {
+                        debug!("Path {index} has reached target!");
                         return Ok(path.iteration);
                     }
                 }
             }
         }
 
-        println!(
+        trace!(
             "Iteration [{iteration}] - Removing {} paths",
             path_indices_to_remove.len()
         );
-        println!(
+        trace!(
             "Iteration [{iteration}] - Adding {} new paths",
             new_paths.len()
         );
@@ -108,12 +160,19 @@ pub fn find_shortest_path(filename: &str) -> Result<usize, Box<dyn std::error::E
     }
 }
 
-pub fn find_shortest_path_from_any_lowest_point(
-    filename: &str,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
-    let hill_climb = HillClimb::try_from(content.as_str())?;
+pub fn find_shortest_path_from_any_lowest_point(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_shortest_path_from_any_lowest_point_from_input(&content)
+}
 
+pub fn find_shortest_path_from_any_lowest_point_from_input(content: &str) -> Result<usize, Error> {
+    shortest_path_from_any_lowest_point(&HillClimb::try_from(content)?, &CancelToken::default())
+}
+
+fn shortest_path_from_any_lowest_point(
+    hill_climb: &HillClimb,
+    cancel: &CancelToken,
+) -> Result<usize, Error> {
     let mut paths: HashMap<usize, HillPath> = HashMap::new();
 
     let mut global_path_index = 0;
@@ -130,11 +189,18 @@ pub fn find_shortest_path_from_any_lowest_point(
 
     let mut iteration = 0;
     loop {
+        cancel.check(|| {
+            format!(
+                "{} steps explored, {} positions visited",
+                iteration,
+                visited_indices.len()
+            )
+        })?;
         iteration += 1;
 
         let mut new_paths: Vec<HillPath> = vec![];
 
-        println!("Iteration [{iteration}] - I iterate with {} paths", {
+        trace!("Iteration [{iteration}] - I iterate with {} paths", {
             paths.len()
         });
 
@@ -142,7 +208,7 @@ pub fn find_shortest_path_from_any_lowest_point(
             return successful_path_lengths
                 .into_iter()
                 .min()
-                .ok_or("Target path has not been reached :(".into());
+                .ok_or(Error::no_solution("Target path has not been reached :("));
         }
 
         let mut path_indices_to_remove = vec![];
@@ -151,7 +217,7 @@ pub fn find_shortest_path_from_any_lowest_point(
             // println!("I iterate with path #{index}");
 
             let possibilities = path
-                .derive_possibilities(&hill_climb)
+                .derive_possibilities(hill_climb)
                 .into_iter()
                 .filter(|p| {
                     let existing_iteration_record = visited_indices.get(p);
@@ -173,10 +239,10 @@ pub fn find_shortest_path_from_any_lowest_point(
                     path_indices_to_remove.push(*index);
                 }
                 1 => {
-                    path.visit(possibilities[0], &hill_climb);
+                    path.visit(possibilities[0], hill_climb);
                     visited_indices.insert(possibilities[0], path.iteration_since_last_low_point);
-                    if path.has_reached_target(&hill_climb) {
-                        println!("Path {index} has reached target!");
+                    if path.has_reached_target(hill_climb) {
+                        trace!("Path {index} has reached target!");
                         successful_path_lengths.push(path.iteration_since_last_low_point);
                         path_indices_to_remove.push(*index);
                     }
@@ -184,20 +250,20 @@ pub fn find_shortest_path_from_any_lowest_point(
                 l => {
                     for i in 1..l {
                         let mut new_path = path.clone();
-                        new_path.visit(possibilities[i], &hill_climb);
+                        new_path.visit(possibilities[i], hill_climb);
                         visited_indices
                             .insert(possibilities[i], new_path.iteration_since_last_low_point);
-                        if new_path.has_reached_target(&hill_climb) {
-                            println!("Path {index} has reached target!");
+                        if new_path.has_reached_target(hill_climb) {
+                            trace!("Path {index} has reached target!");
                             successful_path_lengths.push(new_path.iteration_since_last_low_point);
                         } else {
                             new_paths.push(new_path);
                         }
                     }
-                    path.visit(possibilities[0], &hill_climb);
+                    path.visit(possibilities[0], hill_climb);
                     visited_indices.insert(possibilities[0], path.iteration_since_last_low_point);
-                    if path.has_reached_target(&hill_climb) {
-                        println!("Path {index} has reached target!");
+                    if path.has_reached_target(hill_climb) {
+                        trace!("Path {index} has reached target!");
                         successful_path_lengths.push(path.iteration_since_last_low_point);
                         path_indices_to_remove.push(*index);
                     }
@@ -205,11 +271,11 @@ pub fn find_shortest_path_from_any_lowest_point(
             }
         }
 
-        println!(
+        trace!(
             "Iteration [{iteration}] - Removing {} paths",
             path_indices_to_remove.len()
         );
-        println!(
+        trace!(
             "Iteration [{iteration}] - Adding {} new paths",
             new_paths.len()
         );
@@ -304,6 +370,16 @@ struct HillClimb {
     hill: Vec<Vec<char>>,
 }
 
+impl Puzzle for HillClimb {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(shortest_path(self, &input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(shortest_path_from_any_lowest_point(self, &input.cancel)?.into())
+    }
+}
+
 impl HillClimb {
     fn x_dim(&self) -> usize {
         self.hill[0].len()
@@ -315,12 +391,13 @@ impl HillClimb {
 }
 
 impl TryFrom<&str> for HillClimb {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut hill: Vec<Vec<char>> = vec![];
         let mut starting_position: Option<(usize, usize)> = None;
         let mut target_position: Option<(usize, usize)> = None;
+        let mut errors = ErrorCollector::new();
         for line in value.lines() {
             let mut new_row: Vec<char> = vec![];
             let mut j = 0;
@@ -329,19 +406,21 @@ impl TryFrom<&str> for HillClimb {
                 match c {
                     'S' => {
                         if starting_position.is_some() {
-                            return Err("A second starting position has been found using the character 'S'. This is not supported".into());
+                            errors.report(Error::parse(&c.to_string(), "A second starting position has been found using the character 'S'. This is not supported").at_column(j).at_line(hill.len(), line))?;
+                        } else {
+                            starting_position = Some((hill.len(), j));
                         }
-                        starting_position = Some((hill.len(), j));
                     }
                     'E' => {
                         if target_position.is_some() {
-                            return Err("A second target position has been found using the character 'S'. This is not supported".into());
+                            errors.report(Error::parse(&c.to_string(), "A second target position has been found using the character 'E'. This is not supported").at_column(j).at_line(hill.len(), line))?;
+                        } else {
+                            target_position = Some((hill.len(), j));
                         }
-                        target_position = Some((hill.len(), j));
                     }
                     other => {
                         if !c.is_ascii_lowercase() {
-                            return Err(format!("Invalid hill character has been found, only character between 'a' and 'z' are supported. Got {}", other).into());
+                            errors.report(Error::parse(&other.to_string(), "Invalid hill character has been found, only character between 'a' and 'z' are supported").at_column(j).at_line(hill.len(), line))?;
                         }
                     }
                 }
@@ -352,19 +431,20 @@ impl TryFrom<&str> for HillClimb {
 
             if hill.len() > 0 {
                 if new_row.len() != hill[0].len() {
-                    return Err("Invalid data for the hill construction. Found two lines with two different length".into());
+                    errors.report(Error::parse(line, "Invalid data for the hill construction. Found two lines with two different length").at_line(hill.len(), line))?;
                 }
             }
 
             hill.push(new_row);
         }
+        errors.finish()?;
 
         if starting_position.is_none() {
-            return Err("A starting position has not been found".into());
+            return Err(Error::parse("", "A starting position has not been found"));
         }
 
         if target_position.is_none() {
-            return Err("An ending position has not been found".into());
+            return Err(Error::parse("", "An ending position has not been found"));
         }
 
         Ok(HillClimb {
@@ -382,29 +462,46 @@ mod tests {
     #[test]
     fn example_part_1_has_right_answer() {
         assert_eq!(
-            find_shortest_path("inputs/input-12-example.txt").unwrap(),
+            find_shortest_path("inputs/2022/input-12-example.txt").unwrap(),
             31
         );
     }
 
     #[test]
     fn part_1_has_right_answer() {
-        assert_eq!(find_shortest_path("inputs/input-12.txt").unwrap(), 497);
+        if input_dir::skip_without_key("inputs/2022/input-12.txt") {
+            return;
+        }
+        assert_eq!(find_shortest_path("inputs/2022/input-12.txt").unwrap(), 497);
     }
 
     #[test]
     fn example_part_2_has_right_answer() {
         assert_eq!(
-            find_shortest_path_from_any_lowest_point("inputs/input-12-example.txt").unwrap(),
+            find_shortest_path_from_any_lowest_point("inputs/2022/input-12-example.txt").unwrap(),
             29
         );
     }
 
     #[test]
     fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-12.txt") {
+            return;
+        }
         assert_eq!(
-            find_shortest_path_from_any_lowest_point("inputs/input-12.txt").unwrap(),
+            find_shortest_path_from_any_lowest_point("inputs/2022/input-12.txt").unwrap(),
             492
         );
     }
+
+    #[test]
+    fn cancelled_run_reports_the_explored_steps() {
+        let content = input_dir::read("inputs/2022/input-12-example.txt").unwrap();
+        let cancel = CancelToken::default();
+        cancel.cancel();
+        assert!(matches!(
+            shortest_path(&HillClimb::try_from(content.as_str()).unwrap(), &cancel),
+            Err(Error::Cancelled { .. })
+        ));
+    }
 }
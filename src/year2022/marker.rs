@@ -0,0 +1,199 @@
+use std::{cmp, collections::HashSet, hash};
+
+use crate::{
+    debug, input_dir, trace, Answer, CancelToken, Error, Input, Param, Part, Puzzle, Solver,
+};
+
+pub struct Marker;
+
+impl Solver for Marker {
+    fn name(&self) -> &'static str {
+        "marker"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn module(&self) -> &'static str {
+        "marker"
+    }
+
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(Datastream {
+            content: input.contents.clone(),
+        }))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "a single line of characters"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "length",
+            description:
+                "number of distinct characters of the marker, 4 for part 1 and 14 for part 2",
+            parts: &[Part::Part1, Part::Part2],
+            range: 1..=26,
+        }]
+    }
+}
+
+/// Characters received by the device, searched for markers
+pub struct Datastream {
+    content: String,
+}
+
+impl Puzzle for Datastream {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(find_marker_index(&self.content, input.params.get("length", 4)?, &input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(find_marker_index(
+            &self.content,
+            input.params.get("length", 14)?,
+            &input.cancel,
+        )?
+        .into())
+    }
+}
+
+pub fn find_start_of_packet_marker_index(
+    filename: &str,
+    target_length: usize,
+) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_start_of_packet_marker_index_from_input(&content, target_length)
+}
+
+pub fn find_start_of_packet_marker_index_from_input(
+    content: &str,
+    target_length: usize,
+) -> Result<usize, Error> {
+    find_marker_index(content, target_length, &CancelToken::default())
+}
+
+fn find_marker_index(
+    content: &str,
+    target_length: usize,
+    cancel: &CancelToken,
+) -> Result<usize, Error> {
+    if content.len() < target_length {
+        return Err(Error::parse(
+            content,
+            format!(
+                "The input does not contain enough character to find a marker, found {}",
+                content.len()
+            ),
+        )
+        .with_hint(&format!("at least {} characters", target_length)));
+    }
+
+    let iteration_str_as_chars = content.chars().collect::<Vec<char>>();
+
+    let mut previous_characters = iteration_str_as_chars[0..target_length - 1].to_vec();
+    let mut i = target_length - 1;
+    while !has_unique_elements(&previous_characters) {
+        if i >= iteration_str_as_chars.len() {
+            return Err(Error::no_solution("Unable to find a marker :("));
+        }
+
+        i += 1;
+        previous_characters = iteration_str_as_chars[i + 1 - target_length..i].to_vec();
+    }
+
+    while i < iteration_str_as_chars.len() {
+        cancel.check(|| format!("{} of {} characters read", i, iteration_str_as_chars.len()))?;
+        let c = iteration_str_as_chars[i];
+
+        let existing_position = previous_characters.iter().rev().position(|x| x == &c);
+
+        match existing_position {
+            Some(j) => {
+                let mut jump_size = target_length - j - 1;
+                let mut new_previous_characters = iteration_str_as_chars
+                    [i + 1 - target_length + jump_size..i + jump_size]
+                    .to_vec();
+                while !has_unique_elements(&new_previous_characters) {
+                    jump_size += 1;
+                    new_previous_characters = iteration_str_as_chars
+                        [i + 1 - target_length + jump_size..i + jump_size]
+                        .to_vec();
+                }
+                previous_characters = iteration_str_as_chars
+                    [i + 1 - target_length + jump_size..i + jump_size]
+                    .to_vec();
+                i += jump_size;
+                trace!("Jumping: {}", i);
+            }
+            None => {
+                debug!("{:?}", previous_characters);
+                return Ok(i + 1);
+            }
+        }
+    }
+
+    return Err(Error::no_solution("Unable to find a marker :("));
+}
+
+fn has_unique_elements<T>(iterable: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: cmp::Eq + hash::Hash,
+{
+    let mut set = HashSet::new();
+    return iterable.into_iter().all(|x| set.insert(x));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_target_length_of_4_should_give_expected_answer() {
+        assert_eq!(
+            find_start_of_packet_marker_index("inputs/2022/input-06-example.txt", 4).unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn target_length_of_4_should_give_expected_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-06.txt") {
+            return;
+        }
+        assert_eq!(
+            find_start_of_packet_marker_index("inputs/2022/input-06.txt", 4).unwrap(),
+            1816
+        );
+    }
+
+    #[test]
+    fn example_target_length_of_14_should_give_expected_answer() {
+        assert_eq!(
+            find_start_of_packet_marker_index("inputs/2022/input-06-example.txt", 14).unwrap(),
+            19
+        );
+    }
+
+    #[test]
+    fn target_length_of_14_should_give_expected_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-06.txt") {
+            return;
+        }
+        assert_eq!(
+            find_start_of_packet_marker_index("inputs/2022/input-06.txt", 14).unwrap(),
+            2625
+        );
+    }
+}
@@ -0,0 +1,42 @@
+pub mod assignment;
+pub mod beacon_exclusion_zone;
+pub mod boiling_boulders;
+pub mod callories;
+pub mod cathod_ray_tube;
+pub mod directory;
+pub mod distress_signal;
+pub mod elf_crates;
+pub mod hill_climbing;
+pub mod marker;
+pub mod monkey_in_the_middle;
+pub mod proboscidea_volcanium;
+pub mod pyroclastic_flow;
+pub mod regolith_reservoir;
+pub mod rock_paper_scissors;
+pub mod rope_bridge;
+pub mod rucksacks;
+pub mod tree_house;
+
+use crate::Solver;
+
+/// Registered solvers of 2022, in the ascending order of the days
+pub static REGISTRY: &[&dyn Solver] = &[
+    &callories::Callories,
+    &rock_paper_scissors::RockPaperScissors,
+    &rucksacks::Rucksack,
+    &assignment::Assignment,
+    &elf_crates::ElfCrates,
+    &marker::Marker,
+    &directory::DirectorySizes,
+    &tree_house::TreeHouse,
+    &rope_bridge::RopeBridge,
+    &cathod_ray_tube::CathodRayTube,
+    &monkey_in_the_middle::MonkeyInTheMiddle,
+    &hill_climbing::HillClimbing,
+    &distress_signal::DistressSignal,
+    &regolith_reservoir::RegolithReservoir,
+    &beacon_exclusion_zone::BeaconExclusionZone,
+    &proboscidea_volcanium::ProboscideaVolcanium,
+    &pyroclastic_flow::PyroclasticFlow,
+    &boiling_boulders::BoilingBoulders,
+];
@@ -0,0 +1,704 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, Param, Part, Puzzle, Solver,
+};
+
+pub struct MonkeyInTheMiddle;
+
+impl Solver for MonkeyInTheMiddle {
+    fn name(&self) -> &'static str {
+        "monkey-in-the-middle"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn module(&self) -> &'static str {
+        "monkey_in_the_middle"
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_monkeys(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "notes for each monkey separated by a blank line, starting with `Monkey <id>:`"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "rounds",
+                description: "number of rounds, 20 for part 1 and 10000 for part 2",
+                parts: &[Part::Part1, Part::Part2],
+                range: 0..=1_000_000,
+            },
+            Param {
+                name: "relief",
+                description: "divisor of the worry level after each inspection, 3",
+                parts: &[Part::Part1],
+                range: 1..=1_000,
+            },
+        ]
+    }
+}
+
+/// Monkeys with the items they hold at the start
+pub struct Monkeys {
+    monkeys: Vec<Monkey>,
+}
+
+impl Puzzle for Monkeys {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .monkey_business(
+                input.params.get("rounds", 20)?,
+                input.params.get("relief", 3)?,
+                &input.cancel,
+            )?
+            .into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .big_monkey_business(input.params.get("rounds", 10_000)?, &input.cancel)?
+            .into())
+    }
+}
+
+pub fn parse_monkeys(content: &str) -> Result<Monkeys, Error> {
+    let mut monkeys: Vec<Monkey> = vec![];
+    let mut line_offset = 0;
+    let mut errors = ErrorCollector::new();
+    for monkey_str in content.split("\n\n") {
+        let monkey = Monkey::try_from(monkey_str).map_err(|e| e.shift_lines(line_offset));
+        if let Some(monkey) = errors.check(monkey)? {
+            monkeys.push(monkey);
+        }
+        line_offset += monkey_str.lines().count() + 1;
+    }
+    errors.finish()?;
+
+    Ok(Monkeys { monkeys })
+}
+
+pub fn compute_monkey_business(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    compute_monkey_business_from_input(&content, 20, 3)
+}
+
+pub fn compute_monkey_business_from_input(
+    content: &str,
+    rounds: usize,
+    worry_divider: usize,
+) -> Result<usize, Error> {
+    parse_monkeys(content)?.monkey_business(rounds, worry_divider, &CancelToken::default())
+}
+
+pub fn compute_big_monkey_business(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    compute_big_monkey_business_from_input(&content, 10_000)
+}
+
+pub fn compute_big_monkey_business_from_input(
+    content: &str,
+    rounds: usize,
+) -> Result<usize, Error> {
+    parse_monkeys(content)?.big_monkey_business(rounds, &CancelToken::default())
+}
+
+impl Monkeys {
+    fn monkey_business(
+        &self,
+        rounds: usize,
+        worry_divider: usize,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        let mut monkeys = self.monkeys.clone();
+        let number_of_monkeys = monkeys.len();
+
+        // Without relief the worry levels only matter through the tests, so they are kept modulo the dividers
+        let modulus = if worry_divider == 1 {
+            monkeys
+                .iter()
+                .try_fold(1_usize, |product, m| product.checked_mul(m.test.divider))
+                .filter(|product| *product > 0)
+        } else {
+            None
+        };
+
+        for i in 0..rounds {
+            cancel.check(|| format!("{} of {} rounds played", i, rounds))?;
+            trace!("Round {i}");
+            for i in 0..number_of_monkeys {
+                trace!("  Monkey {i}");
+                let monkey = &mut monkeys[i];
+                trace!("      Has {} items", monkey.items.len());
+                let mut thrown_items = vec![];
+                while monkey.has_items() {
+                    let (thrown_item, destination_monkey_index) =
+                        monkey.inspect_next_item(worry_divider, modulus)?;
+                    thrown_items.push((thrown_item, destination_monkey_index));
+                }
+                for (complexity, destination_index) in thrown_items {
+                    monkeys[destination_index].receive_new_item(complexity);
+                }
+            }
+        }
+
+        let mut counts = monkeys
+            .iter()
+            .map(|m| m.inspected_items_count)
+            .collect::<Vec<usize>>();
+        counts.sort_unstable();
+        counts.reverse();
+
+        Ok(counts[0] * counts[1])
+    }
+
+    fn big_monkey_business(&self, rounds: usize, cancel: &CancelToken) -> Result<usize, Error> {
+        let monkey_reunion = &mut BigMonkeysReunion::from(self.monkeys.as_slice());
+
+        for i in 0..rounds {
+            cancel.check(|| format!("{} of {} rounds played", i, rounds))?;
+            trace!("Round {i}");
+            monkey_reunion.play_round();
+        }
+
+        let mut counts = monkey_reunion
+            .monkeys
+            .iter()
+            .map(|m| m.inspected_items_count)
+            .collect::<Vec<usize>>();
+        counts.sort_unstable();
+        counts.reverse();
+
+        Ok(counts[0] * counts[1])
+    }
+}
+
+#[derive(Debug)]
+struct BigMonkeysReunion {
+    item_test_cache: HashMap<(usize, usize), (usize, usize)>,
+    items: HashMap<usize, BigMonkeyItem>,
+    monkeys: Vec<BigMonkey>,
+}
+
+impl BigMonkeysReunion {
+    fn play_round(&mut self) {
+        for i in 0..self.monkeys.len() {
+            trace!("  Monkey {i}");
+
+            let mut thrown_items = vec![];
+
+            let monkey = &mut self.monkeys[i];
+            trace!("      Has {} items", monkey.items.len());
+
+            for item_id in &monkey.items {
+                let item = self.items.get_mut(&item_id).unwrap();
+                item.operations.push(monkey.operation.clone());
+
+                let remainder = item.remainder(
+                    monkey.test.divider,
+                    self.item_test_cache.get(&(*item_id, i)),
+                );
+                self.item_test_cache
+                    .insert((*item_id, i), (remainder, item.operations.len()));
+
+                let destination_index = if remainder == 0 {
+                    monkey.test.test_true_destination_index
+                } else {
+                    monkey.test.test_false_destination_index
+                };
+
+                thrown_items.push((*item_id, destination_index));
+            }
+
+            monkey.inspected_items_count += monkey.items.len();
+            monkey.items.clear();
+
+            for (item_id, destination_index) in thrown_items {
+                self.monkeys[destination_index].items.push(item_id);
+            }
+        }
+    }
+}
+
+impl From<&[Monkey]> for BigMonkeysReunion {
+    fn from(value: &[Monkey]) -> Self {
+        let mut monkeys: Vec<BigMonkey> = vec![];
+        let mut items = HashMap::<usize, BigMonkeyItem>::new();
+
+        for monkey in value {
+            let mut monkey_initial_items_ids = vec![];
+            for worry_level in &monkey.items {
+                let item_index = items.len();
+                items.insert(
+                    item_index,
+                    BigMonkeyItem {
+                        initial_worry_level: *worry_level,
+                        operations: vec![],
+                    },
+                );
+                monkey_initial_items_ids.push(item_index);
+            }
+
+            monkeys.push(BigMonkey {
+                inspected_items_count: 0,
+                items: monkey_initial_items_ids,
+                operation: monkey.operation.clone(),
+                test: monkey.test.clone(),
+            });
+        }
+
+        BigMonkeysReunion {
+            item_test_cache: HashMap::new(),
+            items,
+            monkeys,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BigMonkey {
+    inspected_items_count: usize,
+    items: Vec<usize>,
+    operation: Operation,
+    test: MonkeyTest,
+}
+
+#[derive(Debug, Clone)]
+struct BigMonkeyItem {
+    initial_worry_level: usize,
+    operations: Vec<Operation>,
+}
+
+// Thread implementation
+impl BigMonkeyItem {
+    fn remainder(&self, divider: usize, cache: Option<&(usize, usize)>) -> usize {
+        let mut remainder = cache
+            .and_then(|c| Some(c.0))
+            .unwrap_or(self.initial_worry_level % divider);
+        let start_index = cache.and_then(|c| Some(c.1)).unwrap_or(0);
+        for i in start_index..self.operations.len() {
+            let op = &self.operations[i];
+            match op {
+                Operation::Addition(v) => match v {
+                    OperationValue::Itself => {
+                        remainder = (remainder * 2) % divider;
+                    }
+                    OperationValue::Value(n) => {
+                        remainder = (remainder + n) % divider;
+                    }
+                },
+                Operation::Multiplication(v) => match v {
+                    OperationValue::Itself => {
+                        remainder = (remainder * remainder) % divider;
+                    }
+                    OperationValue::Value(n) => {
+                        remainder = (remainder * n) % divider;
+                    }
+                },
+            }
+        }
+        remainder
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    inspected_items_count: usize,
+    items: VecDeque<usize>,
+    operation: Operation,
+    test: MonkeyTest,
+}
+
+impl Monkey {
+    fn has_items(&self) -> bool {
+        return self.items.len() > 0;
+    }
+
+    fn inspect_next_item(
+        &mut self,
+        worry_divider: usize,
+        modulus: Option<usize>,
+    ) -> Result<(usize, usize), Error> {
+        let item = self
+            .items
+            .pop_front()
+            .ok_or(Error::invalid_state("No next item found"))?;
+
+        self.inspected_items_count += 1;
+
+        trace!("  Monkey inspects an item with a worry level of {item}.");
+
+        let mut new_worry_level = self.compute_new_worry_level(item)?;
+        trace!("      New worry level is {new_worry_level}.");
+
+        new_worry_level = new_worry_level / worry_divider;
+        trace!("      Monkey gets bored with item. Worry level is divided by {worry_divider} to {new_worry_level}.");
+
+        if let Some(modulus) = modulus {
+            new_worry_level %= modulus;
+        }
+
+        let pass_test = &new_worry_level % self.test.divider == 0_usize;
+
+        if pass_test {
+            trace!(
+                "      Current worry level is divisible by {}.",
+                self.test.divider
+            );
+            trace!(
+                "      Item with worry level {} is thrown to monkey {}.",
+                new_worry_level,
+                self.test.test_true_destination_index
+            );
+            return Ok((new_worry_level, self.test.test_true_destination_index));
+        } else {
+            trace!(
+                "      Current worry level is divisible by {}.",
+                self.test.divider
+            );
+            trace!(
+                "      Item with worry level {} is thrown to monkey {}.",
+                new_worry_level,
+                self.test.test_false_destination_index
+            );
+            return Ok((new_worry_level, self.test.test_false_destination_index));
+        }
+    }
+
+    fn receive_new_item(&mut self, item: usize) {
+        self.items.push_back(item);
+    }
+
+    fn compute_new_worry_level(&self, item: usize) -> Result<usize, Error> {
+        let new_worry_level = match &self.operation {
+            Operation::Addition(v) => match v {
+                OperationValue::Itself => item.checked_mul(2),
+                OperationValue::Value(n) => item.checked_add(*n),
+            },
+            Operation::Multiplication(v) => match v {
+                OperationValue::Itself => item.checked_mul(item),
+                OperationValue::Value(n) => item.checked_mul(*n),
+            },
+        };
+        new_worry_level.ok_or(Error::invalid_state(format!(
+            "Worry level of item {} overflows with operation {:?}",
+            item, self.operation
+        )))
+    }
+}
+
+impl TryFrom<&str> for Monkey {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = value.lines().enumerate();
+
+        // Skip first line
+        lines.next();
+
+        // Second line is for the matching items
+        let (index, raw_line) = lines.next().ok_or(Error::parse(
+            "",
+            "Line for matching items definition not found",
+        ))?;
+        let second_line = raw_line.trim().trim_end();
+        let items_prefix = "Starting items:";
+        if !second_line.starts_with(items_prefix) {
+            return Err(Error::parse(
+                second_line,
+                format!("Invalid line, expected {} <items>", items_prefix),
+            )
+            .at_line(index, raw_line));
+        }
+        let items = second_line
+            .strip_prefix(items_prefix)
+            .or(Some(""))
+            .unwrap()
+            .split(",")
+            .map(|x| parse_value::<usize>(x.trim(), "the item to be a number"))
+            .collect::<Result<Vec<usize>, Error>>()
+            .map_err(|e| e.at_line(index, raw_line))?;
+
+        // Third line is for the operation
+        let (index, raw_line) = lines
+            .next()
+            .ok_or(Error::parse("", "Line for operation definition not found"))?;
+        let third_line = raw_line.trim().trim_end();
+        let operation = Operation::try_from(third_line).map_err(|e| e.at_line(index, raw_line))?;
+
+        // Remaining lines are for the test
+        let monkey_test_lines = lines
+            .map(|(_, line)| line)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let monkey_test = MonkeyTest::try_from(monkey_test_lines.as_str())
+            .map_err(|e| e.shift_lines(index + 1))?;
+
+        Ok(Monkey {
+            inspected_items_count: 0,
+            items: VecDeque::from(items),
+            operation,
+            test: monkey_test,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MonkeyTest {
+    divider: usize,
+    test_true_destination_index: usize,
+    test_false_destination_index: usize,
+}
+
+impl TryFrom<&str> for MonkeyTest {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = value.lines().enumerate();
+
+        // First line is for the divider of the test
+        let (index, raw_line) = lines.next().ok_or(Error::parse(
+            "",
+            "Line for test divider definition not found",
+        ))?;
+        let first_line = raw_line.trim().trim_end();
+        let divider_prefix = "Test: divisible by";
+        if !first_line.starts_with(divider_prefix) {
+            return Err(Error::parse(
+                first_line,
+                format!("Invalid line, expected {} <divider>", divider_prefix),
+            )
+            .at_line(index, raw_line));
+        }
+        let raw_value = first_line
+            .strip_prefix(divider_prefix)
+            .unwrap_or("")
+            .trim()
+            .trim_end();
+        let divider = parse_value::<usize>(raw_value, "the divider to be a number")
+            .map_err(|e| e.at_line(index, raw_line))?;
+
+        // Second line is for the destination if test succeeds
+        let (index, raw_line) = lines.next().ok_or(Error::parse(
+            "",
+            "Line for test success destination definition not found",
+        ))?;
+        let second_line = raw_line.trim().trim_end();
+        let true_destination_prefix = "If true: throw to monkey";
+        if !second_line.starts_with(true_destination_prefix) {
+            return Err(Error::parse(
+                second_line,
+                format!(
+                    "Invalid line, expected {} <monkey index>",
+                    true_destination_prefix
+                ),
+            )
+            .at_line(index, raw_line));
+        }
+        let raw_value = second_line
+            .strip_prefix(true_destination_prefix)
+            .unwrap_or("")
+            .trim()
+            .trim_end();
+        let test_true_destination_index = parse_value::<usize>(
+            raw_value,
+            "the monkey test true destination index to be a number",
+        )
+        .map_err(|e| e.at_line(index, raw_line))?;
+
+        // Third line is for the destination if test fails
+        let (index, raw_line) = lines.next().ok_or(Error::parse(
+            "",
+            "Line for test failure destination definition not found",
+        ))?;
+        let third_line = raw_line.trim().trim_end();
+        let false_destination_prefix = "If false: throw to monkey";
+        if !third_line.starts_with(false_destination_prefix) {
+            return Err(Error::parse(
+                third_line,
+                format!(
+                    "Invalid line, expected {} <monkey index>",
+                    false_destination_prefix
+                ),
+            )
+            .at_line(index, raw_line));
+        }
+        let raw_value = third_line
+            .strip_prefix(false_destination_prefix)
+            .unwrap_or("")
+            .trim()
+            .trim_end();
+        let test_false_destination_index = parse_value::<usize>(
+            raw_value,
+            "the monkey test false destination index to be a number",
+        )
+        .map_err(|e| e.at_line(index, raw_line))?;
+
+        Ok(MonkeyTest {
+            divider,
+            test_false_destination_index,
+            test_true_destination_index,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum OperationValue {
+    Itself,
+    Value(usize),
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Addition(OperationValue),
+    Multiplication(OperationValue),
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let operation_prefix = "Operation: new = old";
+        if !value.starts_with(operation_prefix) {
+            return Err(Error::parse(
+                value,
+                format!(
+                    "Invalid value for Operation, expected `{} <+ x | * x>`",
+                    operation_prefix
+                ),
+            ));
+        }
+
+        let elements = value
+            .strip_prefix(operation_prefix)
+            .unwrap_or("")
+            .trim()
+            .trim_end()
+            .split(" ")
+            .collect::<Vec<&str>>();
+        if elements.len() != 2 {
+            return Err(Error::parse(
+                value,
+                format!(
+                    "Invalid value for Operation, expected `{} <+ x | * x>`",
+                    operation_prefix
+                ),
+            ));
+        }
+
+        let operation_value = match elements[1] {
+            "old" => OperationValue::Itself,
+            other => {
+                let value = parse_value::<usize>(other, "the operation value to be a number")?;
+                OperationValue::Value(value)
+            }
+        };
+
+        match elements[0] {
+            "+" => Ok(Operation::Addition(operation_value)),
+            "*" => Ok(Operation::Multiplication(operation_value)),
+            other => Err(Error::parse(
+                other,
+                "Unsupported symbol for operation, expected `+` or `*`",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_should_give_expected_result() {
+        assert_eq!(
+            compute_monkey_business("inputs/2022/input-11-example.txt").unwrap(),
+            10605
+        );
+    }
+
+    #[test]
+    fn part_1_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-11.txt") {
+            return;
+        }
+        assert_eq!(
+            compute_monkey_business("inputs/2022/input-11.txt").unwrap(),
+            110264
+        );
+    }
+
+    #[test]
+    fn example_part_2_should_give_expected_result() {
+        assert_eq!(
+            compute_big_monkey_business("inputs/2022/input-11-example.txt").unwrap(),
+            2713310158
+        );
+    }
+
+    #[test]
+    fn part_2_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-11.txt") {
+            return;
+        }
+        assert_eq!(
+            compute_big_monkey_business("inputs/2022/input-11.txt").unwrap(),
+            23612457316
+        );
+    }
+
+    #[test]
+    fn worry_levels_without_relief_are_kept_small() {
+        let solver = MonkeyInTheMiddle;
+        let params =
+            crate::Params::parse(&solver, &[Part::Part1], &["relief=1".to_string()]).unwrap();
+        let input = Input::read("inputs/2022/input-11-example.txt", crate::InputSet::EXAMPLE)
+            .unwrap()
+            .with_params(params);
+        assert_eq!(
+            solver.solve(&Part::Part1, &input).unwrap().to_string(),
+            "10197"
+        );
+    }
+
+    #[test]
+    fn overflowing_worry_levels_are_reported() {
+        let error = compute_monkey_business_from_input(
+            &input_dir::read("inputs/2022/input-11-example.txt").unwrap(),
+            20,
+            2,
+        )
+        .unwrap_err();
+        assert!(matches!(error, Error::InvalidState(_)));
+    }
+
+    #[test]
+    fn long_games_are_stopped_by_the_timeout() {
+        let solver = MonkeyInTheMiddle;
+        let params =
+            crate::Params::parse(&solver, &[Part::Part2], &["rounds=1_000_000".to_string()])
+                .unwrap();
+        let input = Input::read("inputs/2022/input-11-example.txt", crate::InputSet::EXAMPLE)
+            .unwrap()
+            .with_params(params)
+            .with_cancel(CancelToken::new(Some(std::time::Duration::from_millis(20))));
+        let error = solver.solve(&Part::Part2, &input).unwrap_err();
+        assert!(matches!(error, Error::TimedOut { .. }));
+        assert!(error.to_string().contains("of 1000000 rounds played"));
+    }
+}
@@ -5,26 +5,161 @@ use std::{
 
 use regex::Regex;
 
+use crate::{
+    debug,
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, Param, Part, Puzzle, Solver,
+};
+
+pub struct ProboscideaVolcanium;
+
+impl Solver for ProboscideaVolcanium {
+    fn name(&self) -> &'static str {
+        "proboscidea-volcanium"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn module(&self) -> &'static str {
+        "proboscidea_volcanium"
+    }
+
+    fn title(&self) -> &'static str {
+        "Proboscidea Volcanium"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_valves(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one valve per line, e.g. `Valve AA has flow rate=0; tunnels lead to valves DD, II`"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "minutes",
+                description: "minutes before the eruption, 30 for part 1 and 26 for part 2",
+                parts: &[Part::Part1, Part::Part2],
+                range: 1..=60,
+            },
+            Param {
+                name: "actors",
+                description: "number of actors opening the valves, 1 for part 1 and 2 for part 2",
+                parts: &[Part::Part1, Part::Part2],
+                range: 1..=2,
+            },
+        ]
+    }
+}
+
 pub fn find_most_released_pressure(
     filename: &str,
     available_minutes: usize,
     number_of_actors: usize,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_most_released_pressure_from_input(&content, available_minutes, number_of_actors)
+}
+
+pub fn find_most_released_pressure_from_input(
+    content: &str,
+    available_minutes: usize,
+    number_of_actors: usize,
+) -> Result<usize, Error> {
+    most_released_pressure(
+        &parse_valves(content)?,
+        available_minutes,
+        number_of_actors,
+        &CancelToken::default(),
+    )
+}
+
+/// Valves of the volcano, with the count and the total rate of the valves worth opening
+pub struct Valves {
+    valves: HashMap<usize, Valve>,
+    worthy_valves_count: usize,
+    max_release_pressure_rate: usize,
+}
+
+impl Puzzle for Valves {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(most_released_pressure(
+            self,
+            input.params.get("minutes", 30)?,
+            input.params.get("actors", 1)?,
+            &input.cancel,
+        )?
+        .into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(most_released_pressure(
+            self,
+            input.params.get("minutes", 26)?,
+            input.params.get("actors", 2)?,
+            &input.cancel,
+        )?
+        .into())
+    }
+}
+
+pub fn parse_valves(content: &str) -> Result<Valves, Error> {
     let mut valves = HashMap::new();
     let mut worthy_valves_count = 0;
     let mut max_release_pressure_rate = 0;
-    for line in content.lines() {
-        println!("{line}");
-        let valve = Valve::try_from(line)?;
+    let mut errors = ErrorCollector::new();
+    for (index, line) in content.lines().enumerate() {
+        trace!("{line}");
+        let Some(valve) =
+            errors.check(Valve::try_from(line).map_err(|e| e.at_line(index, line)))?
+        else {
+            continue;
+        };
         if valve.rate > 0 {
             worthy_valves_count += 1;
             max_release_pressure_rate += valve.rate;
         }
         valves.insert(valve.id, valve);
     }
+    errors.finish()?;
+    if valves.is_empty() {
+        return Err(Error::parse(
+            "",
+            "Empty valve list, expected at least the starting valve `AA`",
+        ));
+    }
+    if !valves.contains_key(&str_to_valve_id("AA")?) {
+        return Err(Error::parse("", "Missing the starting valve `AA`"));
+    }
+
+    Ok(Valves {
+        valves,
+        worthy_valves_count,
+        max_release_pressure_rate,
+    })
+}
 
-    println!("Max release pressure rate: {max_release_pressure_rate}");
+fn most_released_pressure(
+    scan: &Valves,
+    available_minutes: usize,
+    number_of_actors: usize,
+    cancel: &CancelToken,
+) -> Result<usize, Error> {
+    let valves = &scan.valves;
+    let worthy_valves_count = scan.worthy_valves_count;
+
+    debug!(
+        "Max release pressure rate: {}",
+        scan.max_release_pressure_rate
+    );
 
     let mut minutes = 1;
 
@@ -50,8 +185,8 @@ pub fn find_most_released_pressure(
     let mut iteration_per_minutes = 0;
 
     while minutes <= available_minutes {
-        println!("#### MINUTE {minutes} ####");
-        println!("Number of paths {}", paths.len());
+        debug!("#### MINUTE {minutes} ####");
+        debug!("Number of paths {}", paths.len());
 
         let mut paths_to_be_added = vec![];
         let mut paths_to_be_removed = vec![];
@@ -59,6 +194,14 @@ pub fn find_most_released_pressure(
         let mut removed_paths = 0;
 
         for (i, path) in &mut paths {
+            cancel.check(|| {
+                format!(
+                    "{} of {} minutes simulated, {} paths explored",
+                    minutes - 1,
+                    available_minutes,
+                    iteration + iteration_per_minutes
+                )
+            })?;
             iteration_per_minutes += 1;
             path.accumulate_released_pressure();
 
@@ -126,13 +269,13 @@ pub fn find_most_released_pressure(
                 // println!("[Path {i}] - [Actor {actor_index}] - Operate on #{} paths", actor_paths.len());
 
                 for actor_path in &mut actor_paths {
-                    let valve_can_be_opened = actor_path.can_open_valve(&valves, actor_index);
+                    let valve_can_be_opened = actor_path.can_open_valve(valves, actor_index);
 
                     // If we can't open the valve, we create the new paths based on the moving possibilities
                     if !valve_can_be_opened {
                         // REMIND ME: Could filter out loops
                         actor_path.actors[actor_index]
-                            .next_valves_possibilites(&valves)
+                            .next_valves_possibilites(valves)
                             .iter()
                             .map(|next_valve_id| {
                                 let mut new_path = actor_path.clone();
@@ -198,7 +341,7 @@ pub fn find_most_released_pressure(
                         let is_opening_last_valve =
                             actor_path.open_valves_count() == worthy_valves_count - 1;
                         if is_opening_last_valve {
-                            actor_path.open_valve(&valves, actor_index);
+                            actor_path.open_valve(valves, actor_index);
                             actor_path.stop(remaining_minutes);
                             let mut p = actor_path.opening_path.clone();
                             p.extend(actor_path.actor_positions_addendum());
@@ -208,7 +351,7 @@ pub fn find_most_released_pressure(
                             opening_records.insert(o_p, actor_path.released_pressure);
                             // println!("Path {i} - Path {} - All valves open with released pressure: {}", actor_path.opening_path, actor_path.released_pressure);
                             if actor_path.released_pressure > maximum_released_pressure {
-                                println!(
+                                trace!(
                                     "Path {i} - Path {:?} - Found new maximum at: {}. Rate {}",
                                     actor_path.opening_path,
                                     actor_path.released_pressure,
@@ -221,7 +364,7 @@ pub fn find_most_released_pressure(
                         }
 
                         actor_path.actors[actor_index]
-                            .next_valves_possibilites(&valves)
+                            .next_valves_possibilites(valves)
                             .iter()
                             .map(|next_valve_id| {
                                 let mut new_path = actor_path.clone();
@@ -233,7 +376,7 @@ pub fn find_most_released_pressure(
                             });
 
                         let mut opening_valve_possibility = actor_path.clone();
-                        opening_valve_possibility.open_valve(&valves, actor_index);
+                        opening_valve_possibility.open_valve(valves, actor_index);
                         let mut p = opening_valve_possibility.opening_path.clone();
                         p.extend(opening_valve_possibility.actor_positions_addendum());
                         opening_paths.insert(p);
@@ -269,9 +412,8 @@ pub fn find_most_released_pressure(
             paths.insert(path_index, path_to_be_added);
         }
 
-        println!("Iteration in the minute {iteration_per_minutes}");
-        println!("Removed paths in the minute {}", removed_paths);
-        println!("\n");
+        debug!("Iteration in the minute {iteration_per_minutes}");
+        debug!("Removed paths in the minute {}", removed_paths);
         iteration += iteration_per_minutes;
         iteration_per_minutes = 0;
         minutes += 1;
@@ -279,7 +421,7 @@ pub fn find_most_released_pressure(
 
     for p in paths.values() {
         if p.released_pressure > maximum_released_pressure {
-            println!(
+            trace!(
                 "Found one at the end! {}, #{}",
                 p.released_pressure,
                 p.open_valves_count()
@@ -288,7 +430,7 @@ pub fn find_most_released_pressure(
         }
     }
 
-    println!("Iteration: {iteration}");
+    debug!("Iteration: {iteration}");
 
     Ok(maximum_released_pressure)
 }
@@ -465,35 +607,37 @@ struct Valve {
 }
 
 impl TryFrom<&str> for Valve {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let pattern = Regex::new(
             r"Valve ([A-Z]{2}) has flow rate=(\d+);(?: tunnels lead to valves ((?:[A-Z]{2}, )*[A-Z]{2}))?(?: tunnel leads to valve ([A-Z]{2}))?",
-        )?;
+        )
+        .map_err(|e| Error::invalid_state(format!("Invalid valve pattern: {}", e)))?;
         let captures = pattern.captures(value)
-            .ok_or(format!("Unable to parse line into sensor, expected line of format `Valve <double capital letter value> has flow rate=<usize value>; tunnel(s) lead to valve(s) <comma separated list of double capital letter values>`. Got `{}`", value))?;
+            .ok_or(Error::parse(value, "Unable to parse line into valve, expected line of format `Valve <double capital letter value> has flow rate=<usize value>; tunnel(s) lead to valve(s) <comma separated list of double capital letter values>`"))?;
         if captures.len() < 3 {
-            return Err(format!("Unable to parse line into sensor, expected line of format `Valve <double capital letter value> has flow rate=<usize value>; tunnel(s) lead to valve(s) <comma separated list of double capital letter values>`. Got `{}`", value).into());
+            return Err(Error::parse(value, "Unable to parse line into valve, expected line of format `Valve <double capital letter value> has flow rate=<usize value>; tunnel(s) lead to valve(s) <comma separated list of double capital letter values>`"));
         }
         let valve_id = str_to_valve_id(&captures[1])?;
-        let rate = captures[2].parse::<usize>().map_err(|e| {
-            format!(
-                "Invalid numerical value for the `rate` of the valve. Got err {}",
-                e
-            )
-        })?;
+        let rate = parse_value::<usize>(
+            &captures[2],
+            "a numerical value for the `rate` of the valve",
+        )?;
 
         let connected_valves = if let Some(valves_list) = captures.get(3) {
             valves_list
                 .as_str()
                 .split(", ")
                 .map(|s| str_to_valve_id(s))
-                .collect::<Result<Vec<usize>, Box<dyn std::error::Error>>>()?
+                .collect::<Result<Vec<usize>, Error>>()?
         } else if let Some(single_valve) = captures.get(4) {
             vec![str_to_valve_id(single_valve.as_str())?]
         } else {
-            return Err(format!("Need some tunnels for valve {valve_id}").into());
+            return Err(Error::parse(
+                value,
+                format!("Need some tunnels for valve {}", &captures[1]),
+            ));
         };
 
         Ok(Valve {
@@ -504,12 +648,12 @@ impl TryFrom<&str> for Valve {
     }
 }
 
-fn str_to_valve_id(s: &str) -> Result<usize, Box<dyn std::error::Error>> {
+fn str_to_valve_id(s: &str) -> Result<usize, Error> {
     let mut id = 0usize;
     for c in s.to_lowercase().chars() {
         match c.to_digit(36) {
             None => {
-                return Err(format!("Unable to parse value {}", c).into());
+                return Err(Error::parse(s, format!("Unable to parse value {}", c)));
             }
             Some(x) => id = id * 36 + x as usize,
         };
@@ -524,15 +668,18 @@ mod tests {
     #[test]
     fn example_part_1_should_give_expected_result() {
         assert_eq!(
-            find_most_released_pressure("inputs/input-16-example.txt", 30, 1).unwrap(),
+            find_most_released_pressure("inputs/2022/input-16-example.txt", 30, 1).unwrap(),
             1651
         );
     }
 
     #[test]
     fn part_1_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-16.txt") {
+            return;
+        }
         assert_eq!(
-            find_most_released_pressure("inputs/input-16.txt", 30, 1).unwrap(),
+            find_most_released_pressure("inputs/2022/input-16.txt", 30, 1).unwrap(),
             2181
         );
     }
@@ -540,16 +687,30 @@ mod tests {
     #[test]
     fn example_part_2_should_give_expected_result() {
         assert_eq!(
-            find_most_released_pressure("inputs/input-16-example.txt", 26, 2).unwrap(),
+            find_most_released_pressure("inputs/2022/input-16-example.txt", 26, 2).unwrap(),
             1707
         );
     }
 
     #[test]
     fn part_2_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-16.txt") {
+            return;
+        }
         assert_eq!(
-            find_most_released_pressure("inputs/input-16.txt", 26, 2).unwrap(),
+            find_most_released_pressure("inputs/2022/input-16.txt", 26, 2).unwrap(),
             2824
         );
     }
+
+    #[test]
+    fn valve_lists_without_the_starting_valve_are_reported() {
+        assert!(matches!(parse_valves("").err().unwrap(), Error::Parse(_)));
+        let error = parse_valves("Valve BB has flow rate=13; tunnel leads to valve BB\n")
+            .err()
+            .unwrap();
+        assert!(error
+            .to_string()
+            .contains("Missing the starting valve `AA`"));
+    }
 }
@@ -1,93 +1,189 @@
 use std::{self, collections::HashMap};
 
-pub fn find_tower_height(
-    filename: &str,
-    number_of_rocks: usize,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+use crate::{
+    debug, input_dir, trace, Answer, CancelToken, Error, Input, Param, Part, Puzzle, Solver,
+};
 
-    let mut jets = content
-        .chars()
-        .filter_map(|c| match Jet::try_from(&c) {
-            Ok(d) => Some(d),
-            Err(_) => None,
-        })
-        .cycle();
+pub struct PyroclasticFlow;
 
-    let rocks = define_rocks();
+impl Solver for PyroclasticFlow {
+    fn name(&self) -> &'static str {
+        "pyroclastic-flow"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
 
-    let mut cave = Cave::new();
+    fn day(&self) -> u8 {
+        17
+    }
 
-    let mut jet_index = 0;
-    let mut surface_depth = cave.rocky_surface_depth();
+    fn module(&self) -> &'static str {
+        "pyroclastic_flow"
+    }
 
-    let mut repetitions: HashMap<(usize, usize, usize), Vec<(usize, usize, usize)>> =
-        HashMap::new();
+    fn title(&self) -> &'static str {
+        "Pyroclastic Flow"
+    }
 
-    let mut fallen_rock_count = 0;
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_jet_pattern(&input.contents)?))
+    }
 
-    while fallen_rock_count < number_of_rocks {
-        if fallen_rock_count % 100_000 == 0 {
-            println!("Rock #{fallen_rock_count}");
-        }
-        let rock_type = &rocks[fallen_rock_count % rocks.len()];
-        let mut rock = FallingRock::new(cave.height() + 3, rock_type);
+    fn input_format(&self) -> &'static str {
+        "a single line of jet directions, `<` or `>`"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "rocks",
+            description: "number of fallen rocks, 2022 for part 1 and 1000000000000 for part 2",
+            parts: &[Part::Part1, Part::Part2],
+            range: 1..=i64::MAX,
+        }]
+    }
+}
+
+pub fn find_tower_height(filename: &str, number_of_rocks: usize) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_tower_height_from_input(&content, number_of_rocks)
+}
+
+pub fn find_tower_height_from_input(content: &str, number_of_rocks: usize) -> Result<usize, Error> {
+    parse_jet_pattern(content)?.tower_height(number_of_rocks, &CancelToken::default())
+}
 
-        if let Some(jet) = jets.next() {
-            jet_index += 1;
-            rock.apply_jet(&jet, &cave);
+/// Directions of the jets of hot gas, repeated once exhausted
+pub struct JetPattern {
+    jets: Vec<Jet>,
+}
+
+impl Puzzle for JetPattern {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .tower_height(input.params.get("rocks", 2_022)?, &input.cancel)?
+            .into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .tower_height(input.params.get("rocks", 1_000_000_000_000)?, &input.cancel)?
+            .into())
+    }
+}
+
+pub fn parse_jet_pattern(content: &str) -> Result<JetPattern, Error> {
+    let pattern = content.strip_suffix('\n').unwrap_or(content);
+    let mut jets = vec![];
+    for (index, line) in pattern.split('\n').enumerate() {
+        if index > 0 {
+            return Err(
+                Error::parse(line, "Unexpected line, expected the jets on a single line")
+                    .at_column(0)
+                    .at_line(index, line),
+            );
+        }
+        for (column, c) in line.chars().enumerate() {
+            let jet = Jet::try_from(&c).map_err(|e| e.at_column(column).at_line(index, line))?;
+            jets.push(jet);
         }
-        while cave.can_rock_fall(&rock) {
-            rock.fall();
+    }
+    if jets.is_empty() {
+        return Err(
+            Error::parse("", "Empty jet pattern, expected at least one `<` or `>`").at_line(0, ""),
+        );
+    }
+    Ok(JetPattern { jets })
+}
+
+impl JetPattern {
+    fn tower_height(&self, number_of_rocks: usize, cancel: &CancelToken) -> Result<usize, Error> {
+        let mut jets = self.jets.iter().cycle();
+
+        let rocks = define_rocks();
+
+        let mut cave = Cave::new();
+
+        let mut jet_index = 0;
+        let mut surface_depth = cave.rocky_surface_depth();
+
+        let mut repetitions: HashMap<(usize, usize, usize), Vec<(usize, usize, usize)>> =
+            HashMap::new();
+
+        let mut fallen_rock_count = 0;
+
+        while fallen_rock_count < number_of_rocks {
+            cancel.check(|| {
+                format!(
+                    "{} of {} rocks dropped, the tower being {} high",
+                    fallen_rock_count,
+                    number_of_rocks,
+                    cave.height() - 1
+                )
+            })?;
+            if fallen_rock_count % 100_000 == 0 {
+                debug!("Rock #{fallen_rock_count}");
+            }
+            let rock_type = &rocks[fallen_rock_count % rocks.len()];
+            let mut rock = FallingRock::new(cave.height() + 3, rock_type);
+
             if let Some(jet) = jets.next() {
                 jet_index += 1;
-                rock.apply_jet(&jet, &cave);
+                rock.apply_jet(jet, &cave);
+            }
+            while cave.can_rock_fall(&rock) {
+                rock.fall();
+                if let Some(jet) = jets.next() {
+                    jet_index += 1;
+                    rock.apply_jet(jet, &cave);
+                }
             }
-        }
 
-        cave.incorporate_rock(&rock);
+            cave.incorporate_rock(&rock);
 
-        let new_surface_depth = cave.rocky_surface_depth();
-        if new_surface_depth < surface_depth {
-            let rows_to_truncate =
-                cave.height() - cave.truncated_height - 1 - (new_surface_depth + 1);
-            if rows_to_truncate > 0 {
-                cave.truncate(rows_to_truncate);
-            }
-            let rock_index = fallen_rock_count % rocks.len();
-            let repetition_tuple = (surface_depth, new_surface_depth, rock_index);
-            if let Some(r) = repetitions.get_mut(&repetition_tuple) {
-                if r.len() == 1 {
-                    r.push((fallen_rock_count, jet_index, cave.height()));
-                } else {
-                    let rock_diff = r[1].0 - r[0].0;
-                    let jet_diff = r[1].1 - r[0].1;
-                    let h_diff = r[1].2 - r[0].2;
-                    let repetition_detected = fallen_rock_count - r[1].0 == rock_diff
-                        && jet_index - r[1].1 == jet_diff
-                        && cave.height() - r[1].2 == h_diff;
-                    if repetition_detected {
-                        let q = (number_of_rocks - fallen_rock_count) / rock_diff;
-                        fallen_rock_count += q * rock_diff;
-                        jet_index += q * jet_diff;
-                        cave.truncated_height += q * h_diff;
+            let new_surface_depth = cave.rocky_surface_depth();
+            if new_surface_depth < surface_depth {
+                let rows_to_truncate =
+                    cave.height() - cave.truncated_height - 1 - (new_surface_depth + 1);
+                if rows_to_truncate > 0 {
+                    cave.truncate(rows_to_truncate);
+                }
+                let rock_index = fallen_rock_count % rocks.len();
+                let repetition_tuple = (surface_depth, new_surface_depth, rock_index);
+                if let Some(r) = repetitions.get_mut(&repetition_tuple) {
+                    if r.len() == 1 {
+                        r.push((fallen_rock_count, jet_index, cave.height()));
+                    } else {
+                        let rock_diff = r[1].0 - r[0].0;
+                        let jet_diff = r[1].1 - r[0].1;
+                        let h_diff = r[1].2 - r[0].2;
+                        let repetition_detected = fallen_rock_count - r[1].0 == rock_diff
+                            && jet_index - r[1].1 == jet_diff
+                            && cave.height() - r[1].2 == h_diff;
+                        if repetition_detected {
+                            let q = (number_of_rocks - fallen_rock_count) / rock_diff;
+                            fallen_rock_count += q * rock_diff;
+                            jet_index += q * jet_diff;
+                            cave.truncated_height += q * h_diff;
+                        }
                     }
+                } else {
+                    repetitions.insert(
+                        repetition_tuple,
+                        vec![(fallen_rock_count, jet_index, cave.height())],
+                    );
                 }
-            } else {
-                repetitions.insert(
-                    repetition_tuple,
-                    vec![(fallen_rock_count, jet_index, cave.height())],
-                );
             }
-        }
-        surface_depth = new_surface_depth;
+            surface_depth = new_surface_depth;
 
-        fallen_rock_count += 1;
-    }
+            fallen_rock_count += 1;
+        }
 
-    // println!("{}", cave);
+        // println!("{}", cave);
 
-    Ok(cave.height() - 1)
+        Ok(cave.height() - 1)
+    }
 }
 
 struct Cave {
@@ -139,7 +235,7 @@ impl Cave {
                 self.width - (rock.bottom_left_position.x + rock.rock_type.width());
             for i in 0..(rock.bottom_left_position.y + rock.rock_type.height() - self.height()) {
                 if i >= rock.rock_type.height() {
-                    println!("|.......|");
+                    trace!("|.......|");
                 } else {
                     let mut displayed_row = "|".to_owned();
                     for _ in 0..number_of_air_before_the_rock {
@@ -160,11 +256,11 @@ impl Cave {
                         displayed_row += ".";
                     }
                     displayed_row += "|";
-                    println!("{}", displayed_row);
+                    trace!("{}", displayed_row);
                 }
             }
         }
-        println!("{}", self);
+        trace!("{}", self);
     }
 
     fn new() -> Self {
@@ -373,22 +469,30 @@ impl Rock {
 }
 
 impl TryFrom<Vec<Vec<Element>>> for Rock {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: Vec<Vec<Element>>) -> Result<Self, Self::Error> {
         if value.len() == 0 {
-            return Err("Got zero rows, empty rock is not allowed".into());
+            return Err(Error::invalid_state(
+                "Got zero rows, empty rock is not allowed",
+            ));
         }
         let row_size = value[0].len();
         if value.iter().any(|r| r.len() != row_size) {
-            return Err("Rock structure must have rows of same size".into());
+            return Err(Error::invalid_state(
+                "Rock structure must have rows of same size",
+            ));
         }
         // TODO: add also check on column
         if value.iter().any(|r| r.iter().all(|e| e == &Element::Air)) {
-            return Err("Rock can not have row filled with air only".into());
+            return Err(Error::invalid_state(
+                "Rock can not have row filled with air only",
+            ));
         }
         if row_size == 0 {
-            return Err("Gor zero columns, empty rock is not allowed".into());
+            return Err(Error::invalid_state(
+                "Gor zero columns, empty rock is not allowed",
+            ));
         }
         Ok(Rock { structure: value })
     }
@@ -419,10 +523,14 @@ impl RockyPathFinder {
         }
     }
 
-    fn go_back(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn go_back(&mut self) -> Result<(), Error> {
         let previous_position = self.previous.pop();
         match previous_position {
-            None => return Err("Unable to go back as there are no previous position".into()),
+            None => {
+                return Err(Error::invalid_state(
+                    "Unable to go back as there are no previous position",
+                ))
+            }
             Some(p) => {
                 self.current_position = p;
             }
@@ -573,17 +681,16 @@ enum Jet {
 }
 
 impl TryFrom<&char> for Jet {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
 
     fn try_from(value: &char) -> Result<Self, Self::Error> {
         match value {
             '<' => Ok(Jet::Left),
             '>' => Ok(Jet::Right),
-            other => Err(format!(
-                "Invalid provided value for jet, expected '<' or '>', got {}",
-                other
-            )
-            .into()),
+            other => Err(Error::parse(
+                &other.to_string(),
+                "Invalid provided value for jet, expected '<' or '>'",
+            )),
         }
     }
 }
@@ -644,15 +751,18 @@ mod tests {
     #[test]
     fn example_part_1_should_give_expected_result() {
         assert_eq!(
-            find_tower_height("inputs/input-17-example.txt", 2_022).unwrap(),
+            find_tower_height("inputs/2022/input-17-example.txt", 2_022).unwrap(),
             3068
         );
     }
 
     #[test]
     fn part_1_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-17.txt") {
+            return;
+        }
         assert_eq!(
-            find_tower_height("inputs/input-17.txt", 2_022).unwrap(),
+            find_tower_height("inputs/2022/input-17.txt", 2_022).unwrap(),
             3111
         );
     }
@@ -660,16 +770,46 @@ mod tests {
     #[test]
     fn example_part_2_should_give_expected_result() {
         assert_eq!(
-            find_tower_height("inputs/input-17-example.txt", 1_000_000_000_000).unwrap(),
+            find_tower_height("inputs/2022/input-17-example.txt", 1_000_000_000_000).unwrap(),
             1514285714288
         );
     }
 
     #[test]
     fn part_2_should_give_expected_result() {
+        if input_dir::skip_without_key("inputs/2022/input-17.txt") {
+            return;
+        }
         assert_eq!(
-            find_tower_height("inputs/input-17.txt", 1_000_000_000_000).unwrap(),
+            find_tower_height("inputs/2022/input-17.txt", 1_000_000_000_000).unwrap(),
             1526744186042
         );
     }
+
+    #[test]
+    fn invalid_jets_are_located() {
+        let error = parse_jet_pattern("<<>x>\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "1:4: Invalid provided value for jet, expected '<' or '>', got `x`"
+        );
+        assert!(parse_jet_pattern("<>\n>\n").is_err());
+        assert!(parse_jet_pattern("").is_err());
+        assert!(parse_jet_pattern("\n").is_err());
+    }
+
+    #[test]
+    fn cancelled_run_reports_the_dropped_rocks() {
+        let content = input_dir::read("inputs/2022/input-17-example.txt").unwrap();
+        let cancel = CancelToken::default();
+        cancel.cancel();
+        assert_eq!(
+            parse_jet_pattern(&content)
+                .unwrap()
+                .tower_height(2_022, &cancel)
+                .unwrap_err()
+                .to_string(),
+            "Cancelled after 0 of 2022 rocks dropped, the tower being 0 high"
+        );
+    }
 }
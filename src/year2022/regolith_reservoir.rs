@@ -1,74 +1,80 @@
 use std;
 
-pub fn find_number_of_resting_units_of_sand_before_falling_in_void(
-    filename: &str,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+use crate::{
+    debug,
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
 
-    let mut min_x = usize::MAX;
-    let mut max_x = 0;
-    let mut max_y = 0;
+pub struct RegolithReservoir;
 
-    let mut rock_set = vec![];
-    for line in content.lines() {
-        let mut points = vec![];
-        for raw_point in line.split("->") {
-            let point = Point::try_from(raw_point.trim().trim_end())?;
-            if point.x > max_x {
-                max_x = point.x;
-            }
-            if point.x < min_x {
-                min_x = point.x;
-            }
-            if point.y > max_y {
-                max_y = point.y;
-            }
-            println!("Point: {point}");
-            points.push(point);
-        }
-        rock_set.push(points);
+impl Solver for RegolithReservoir {
+    fn name(&self) -> &'static str {
+        "regolith-reservoir"
     }
 
-    let mut grid = Cave::build_empty_cave(min_x, max_x - min_x + 1, 0, max_y + 1)?;
+    fn year(&self) -> u16 {
+        2022
+    }
 
-    for i in 0..rock_set.len() {
-        for j in 1..rock_set[i].len() {
-            grid.draw_rock_line(&rock_set[i][j - 1], &rock_set[i][j])?;
-        }
+    fn day(&self) -> u8 {
+        14
     }
 
-    println!("Grid {grid}");
-    println!("Minimum x {min_x}");
-    println!("Maximum x {max_x}");
+    fn module(&self) -> &'static str {
+        "regolith_reservoir"
+    }
 
-    let mut sand_unit_stable_count = 0;
-    loop {
-        match grid.let_sand_unit_fall()? {
-            FallPosition::Void => {
-                println!("A sand unit has fallen into the void! Stopping there. Printing snaphot of the grid:\n{grid}");
-                return Ok(sand_unit_stable_count);
-            }
-            FallPosition::Point(_) => {
-                sand_unit_stable_count += 1;
-            }
-        };
+    fn title(&self) -> &'static str {
+        "Regolith Reservoir"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_rock_scan(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one rock path per line, e.g. `498,4 -> 498,6 -> 496,6`"
     }
 }
 
-pub fn find_number_of_resting_units_of_sand_before_blocked(
-    filename: &str,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filename)?;
+/// Rock paths of the scan, with the bounds of their points
+pub struct RockScan {
+    rock_set: Vec<Vec<Point>>,
+    min_x: usize,
+    max_x: usize,
+    max_y: usize,
+}
+
+impl Puzzle for RockScan {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .resting_units_of_sand_before_falling_in_void(&input.cancel)?
+            .into())
+    }
 
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .resting_units_of_sand_before_blocked(&input.cancel)?
+            .into())
+    }
+}
+
+pub fn parse_rock_scan(content: &str) -> Result<RockScan, Error> {
     let mut min_x = usize::MAX;
     let mut max_x = 0;
     let mut max_y = 0;
 
     let mut rock_set = vec![];
-    for line in content.lines() {
+    let mut errors = ErrorCollector::new();
+    for (index, line) in content.lines().enumerate() {
         let mut points = vec![];
         for raw_point in line.split("->") {
-            let point = Point::try_from(raw_point.trim().trim_end())?;
+            let point =
+                Point::try_from(raw_point.trim().trim_end()).map_err(|e| e.at_line(index, line));
+            let Some(point) = errors.check(point)? else {
+                continue;
+            };
             if point.x > max_x {
                 max_x = point.x;
             }
@@ -78,48 +84,124 @@ pub fn find_number_of_resting_units_of_sand_before_blocked(
             if point.y > max_y {
                 max_y = point.y;
             }
-            println!("Point: {point}");
+            trace!("Point: {point}");
             points.push(point);
         }
         rock_set.push(points);
     }
+    errors.finish()?;
+
+    Ok(RockScan {
+        rock_set,
+        min_x,
+        max_x,
+        max_y,
+    })
+}
+
+pub fn find_number_of_resting_units_of_sand_before_falling_in_void(
+    filename: &str,
+) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_number_of_resting_units_of_sand_before_falling_in_void_from_input(&content)
+}
 
-    max_y += 2;
+pub fn find_number_of_resting_units_of_sand_before_falling_in_void_from_input(
+    content: &str,
+) -> Result<usize, Error> {
+    parse_rock_scan(content)?.resting_units_of_sand_before_falling_in_void(&CancelToken::default())
+}
 
-    let theoretical_sufficient_x_dimension = 2 * max_y + 1;
-    let x_dimension = std::cmp::max(
-        std::cmp::max(theoretical_sufficient_x_dimension, 2 * (500 - min_x) + 1),
-        2 * (max_x - 500) + 1,
-    );
-    let min_x = 500 - (x_dimension - 1) / 2;
-    let max_x = 500 + (x_dimension - 1) / 2;
+pub fn find_number_of_resting_units_of_sand_before_blocked(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_number_of_resting_units_of_sand_before_blocked_from_input(&content)
+}
 
-    let mut grid = Cave::build_empty_cave(min_x, max_x - min_x + 1, 0, max_y + 1)?;
+pub fn find_number_of_resting_units_of_sand_before_blocked_from_input(
+    content: &str,
+) -> Result<usize, Error> {
+    parse_rock_scan(content)?.resting_units_of_sand_before_blocked(&CancelToken::default())
+}
 
-    for i in 0..rock_set.len() {
-        for j in 1..rock_set[i].len() {
-            grid.draw_rock_line(&rock_set[i][j - 1], &rock_set[i][j])?;
+impl RockScan {
+    fn draw_rocks(&self, grid: &mut Cave) -> Result<(), Error> {
+        for rock_path in &self.rock_set {
+            for j in 1..rock_path.len() {
+                grid.draw_rock_line(&rock_path[j - 1], &rock_path[j])?;
+            }
         }
+        Ok(())
     }
 
-    grid.draw_rock_line(&Point { x: min_x, y: max_y }, &Point { x: max_x, y: max_y })?;
+    fn resting_units_of_sand_before_falling_in_void(
+        &self,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        let (min_x, max_x, max_y) = (self.min_x, self.max_x, self.max_y);
 
-    println!("Grid {grid}");
+        let mut grid = Cave::build_empty_cave(min_x, max_x - min_x + 1, 0, max_y + 1)?;
 
-    let mut sand_unit_stable_count = 0;
-    loop {
-        match grid.let_sand_unit_fall()? {
-            FallPosition::Void => {
-                return Err("It is not expected to have sand falling in the void!".into());
-            }
-            FallPosition::Point(p) => {
-                sand_unit_stable_count += 1;
-                if p == grid.sand_starting_point() {
-                    println!("Falling sand is blocked! \n{grid}");
+        self.draw_rocks(&mut grid)?;
+
+        trace!("Grid {grid}");
+        debug!("Minimum x {min_x}");
+        debug!("Maximum x {max_x}");
+
+        let mut sand_unit_stable_count = 0;
+        loop {
+            cancel.check(|| format!("{} units of sand at rest", sand_unit_stable_count))?;
+            match grid.let_sand_unit_fall()? {
+                FallPosition::Void => {
+                    debug!("A sand unit has fallen into the void! Stopping there. Printing snaphot of the grid:\n{grid}");
                     return Ok(sand_unit_stable_count);
                 }
-            }
-        };
+                FallPosition::Point(_) => {
+                    sand_unit_stable_count += 1;
+                }
+            };
+        }
+    }
+
+    fn resting_units_of_sand_before_blocked(&self, cancel: &CancelToken) -> Result<usize, Error> {
+        let max_y = self.max_y + 2;
+
+        let theoretical_sufficient_x_dimension = 2 * max_y + 1;
+        let x_dimension = std::cmp::max(
+            std::cmp::max(
+                theoretical_sufficient_x_dimension,
+                2 * (500 - self.min_x) + 1,
+            ),
+            2 * (self.max_x - 500) + 1,
+        );
+        let min_x = 500 - (x_dimension - 1) / 2;
+        let max_x = 500 + (x_dimension - 1) / 2;
+
+        let mut grid = Cave::build_empty_cave(min_x, max_x - min_x + 1, 0, max_y + 1)?;
+
+        self.draw_rocks(&mut grid)?;
+
+        grid.draw_rock_line(&Point { x: min_x, y: max_y }, &Point { x: max_x, y: max_y })?;
+
+        trace!("Grid {grid}");
+
+        let mut sand_unit_stable_count = 0;
+        loop {
+            cancel.check(|| format!("{} units of sand at rest", sand_unit_stable_count))?;
+            match grid.let_sand_unit_fall()? {
+                FallPosition::Void => {
+                    return Err(Error::invalid_state(
+                        "It is not expected to have sand falling in the void!",
+                    ));
+                }
+                FallPosition::Point(p) => {
+                    sand_unit_stable_count += 1;
+                    if p == grid.sand_starting_point() {
+                        debug!("Falling sand is blocked! \n{grid}");
+                        return Ok(sand_unit_stable_count);
+                    }
+                }
+            };
+        }
     }
 }
 
@@ -146,24 +228,24 @@ impl Cave {
         x_dimension: usize,
         from_y: usize,
         y_dimension: usize,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+    ) -> Result<Self, Error> {
         let x_offset = from_x;
         let y_offset = from_y;
 
         if x_dimension == 0 {
-            return Err("Dimension along X axis can not be 0".into());
+            return Err(Error::invalid_state("Dimension along X axis can not be 0"));
         }
 
         if x_offset + x_dimension >= 1_000 {
-            return Err("Offset or dimension along the X axis is too large, only point until 1000 are supported".into());
+            return Err(Error::invalid_state("Offset or dimension along the X axis is too large, only point until 1000 are supported"));
         }
 
         if y_dimension == 0 {
-            return Err("Dimension along Y axis can not be 0".into());
+            return Err(Error::invalid_state("Dimension along Y axis can not be 0"));
         }
 
         if y_offset + y_dimension >= 1_000 {
-            return Err("Offset or dimension along the Y axis is too large, only point until 1000 are supported".into());
+            return Err(Error::invalid_state("Offset or dimension along the Y axis is too large, only point until 1000 are supported"));
         }
 
         let empty_row = vec![CaveElement::Air; x_dimension];
@@ -239,7 +321,7 @@ impl Cave {
         return false;
     }
 
-    fn let_sand_unit_fall(&mut self) -> Result<FallPosition, Box<dyn std::error::Error>> {
+    fn let_sand_unit_fall(&mut self) -> Result<FallPosition, Error> {
         let mut p = self.sand_starting_point();
 
         loop {
@@ -258,10 +340,10 @@ impl Cave {
         }
     }
 
-    fn draw_rock_line(&mut self, a: &Point, b: &Point) -> Result<(), Box<dyn std::error::Error>> {
+    fn draw_rock_line(&mut self, a: &Point, b: &Point) -> Result<(), Error> {
         if a.x != b.x {
             if a.y != b.y {
-                return Err(format!("Drawing diagonal line is not supported, only vertical and horizontal. Given points are invalid in that regard. Points {} and {}.", a, b).into());
+                return Err(Error::invalid_state(format!("Drawing diagonal line is not supported, only vertical and horizontal. Given points are invalid in that regard. Points {} and {}.", a, b)));
             }
             // Draw along X
             let (start, end) = if a.x > b.x { (b.x, a.x) } else { (a.x, b.x) };
@@ -289,19 +371,21 @@ struct Point {
 }
 
 impl TryFrom<&str> for Point {
-    type Error = Box<dyn std::error::Error>;
+    type Error = Error;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let coordinates = value
             .split(",")
-            .map(|s| s.parse::<usize>())
-            .collect::<Result<Vec<usize>, std::num::ParseIntError>>()?;
+            .map(|s| parse_value::<usize>(s, "a numerical coordinate"))
+            .collect::<Result<Vec<usize>, Error>>()?;
         if coordinates.len() != 2 {
-            return Err(format!(
-                "Need two coordinates to form a point, got {}. Original parsed string was {}",
-                coordinates.len(),
-                value
+            return Err(Error::parse(
+                value,
+                format!(
+                    "Need two coordinates to form a point, found {}",
+                    coordinates.len()
+                ),
             )
-            .into());
+            .with_hint("points of the form `<x>,<y>`"));
         }
         Ok(Point {
             x: coordinates[0],
@@ -369,7 +453,7 @@ mod tests {
     fn example_part_1_should_give_expected_answer() {
         assert_eq!(
             find_number_of_resting_units_of_sand_before_falling_in_void(
-                "inputs/input-14-example.txt"
+                "inputs/2022/input-14-example.txt"
             )
             .unwrap(),
             24
@@ -378,8 +462,11 @@ mod tests {
 
     #[test]
     fn part_1_should_give_expected_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-14.txt") {
+            return;
+        }
         assert_eq!(
-            find_number_of_resting_units_of_sand_before_falling_in_void("inputs/input-14.txt")
+            find_number_of_resting_units_of_sand_before_falling_in_void("inputs/2022/input-14.txt")
                 .unwrap(),
             795
         );
@@ -388,7 +475,7 @@ mod tests {
     #[test]
     fn example_part_2_should_give_expected_answer() {
         assert_eq!(
-            find_number_of_resting_units_of_sand_before_blocked("inputs/input-14-example.txt")
+            find_number_of_resting_units_of_sand_before_blocked("inputs/2022/input-14-example.txt")
                 .unwrap(),
             93
         );
@@ -396,8 +483,12 @@ mod tests {
 
     #[test]
     fn part_2_should_give_expected_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-14.txt") {
+            return;
+        }
         assert_eq!(
-            find_number_of_resting_units_of_sand_before_blocked("inputs/input-14.txt").unwrap(),
+            find_number_of_resting_units_of_sand_before_blocked("inputs/2022/input-14.txt")
+                .unwrap(),
             30214
         );
     }
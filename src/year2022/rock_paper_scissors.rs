@@ -0,0 +1,298 @@
+use crate::{error::ErrorCollector, input_dir, Answer, CancelToken, Error, Input, Puzzle, Solver};
+
+pub struct RockPaperScissors;
+
+impl Solver for RockPaperScissors {
+    fn name(&self) -> &'static str {
+        "rock-paper-scissors"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn module(&self) -> &'static str {
+        "rock_paper_scissors"
+    }
+
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_strategy_guide(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one round per line, `A`, `B` or `C` then `X`, `Y` or `Z`, e.g. `A Y`"
+    }
+}
+
+/// Rounds of the strategy guide, the meaning of the second column depending on the part
+pub struct StrategyGuide {
+    rounds: Vec<(GameChoice, Code)>,
+}
+
+impl Puzzle for StrategyGuide {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.score_with_initial_strategy(&input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.score_with_second_strategy(&input.cancel)?.into())
+    }
+}
+
+impl StrategyGuide {
+    fn score_with_initial_strategy(&self, cancel: &CancelToken) -> Result<u32, Error> {
+        let mut score = 0;
+        for (index, (what_the_other_played, code)) in self.rounds.iter().enumerate() {
+            cancel.check(|| self.progress(index))?;
+            score += Round::new(
+                GameChoice::build_using_first_strategy(code),
+                what_the_other_played.clone(),
+            )
+            .score();
+        }
+        Ok(score)
+    }
+
+    fn score_with_second_strategy(&self, cancel: &CancelToken) -> Result<u32, Error> {
+        let mut score = 0;
+        for (index, (what_the_other_played, code)) in self.rounds.iter().enumerate() {
+            cancel.check(|| self.progress(index))?;
+            let what_i_played =
+                GameChoice::build_using_second_strategy(code, what_the_other_played)?;
+            score += Round::new(what_i_played, what_the_other_played.clone()).score();
+        }
+        Ok(score)
+    }
+
+    fn progress(&self, index: usize) -> String {
+        format!("{} of {} rounds scored", index, self.rounds.len())
+    }
+}
+
+pub fn parse_strategy_guide(contents: &str) -> Result<StrategyGuide, Error> {
+    let mut rounds = vec![];
+    let mut errors = ErrorCollector::new();
+    for (index, line) in contents.lines().enumerate() {
+        let round = parse_round(line).map_err(|e| e.at_line(index, line));
+        if let Some(round) = errors.check(round)? {
+            rounds.push(round);
+        }
+    }
+    errors.finish()?;
+    Ok(StrategyGuide { rounds })
+}
+
+fn parse_round(value: &str) -> Result<(GameChoice, Code), Error> {
+    let choices: Vec<_> = value.trim().trim_end().split(" ").collect();
+
+    if choices.len() != 2 {
+        return Err(Error::parse(
+            value,
+            "Invalid line format, expect '<Letter> <Letter>'",
+        ));
+    }
+
+    let what_the_other_played = GameChoice::build_other_player_choice(choices[0])?;
+    let code = Code::try_from(choices[1])?;
+
+    return Ok((what_the_other_played, code));
+}
+
+pub fn compute_score_with_initial_strategy(filename: &str) -> Result<u32, Error> {
+    let contents = input_dir::read(filename)?;
+    compute_score_with_initial_strategy_from_input(&contents)
+}
+
+pub fn compute_score_with_initial_strategy_from_input(contents: &str) -> Result<u32, Error> {
+    parse_strategy_guide(contents)?.score_with_initial_strategy(&CancelToken::default())
+}
+
+pub fn compute_score_with_second_strategy(filename: &str) -> Result<u32, Error> {
+    let contents = input_dir::read(filename)?;
+    compute_score_with_second_strategy_from_input(&contents)
+}
+
+pub fn compute_score_with_second_strategy_from_input(contents: &str) -> Result<u32, Error> {
+    parse_strategy_guide(contents)?.score_with_second_strategy(&CancelToken::default())
+}
+
+#[derive(Debug, Clone)]
+enum GameChoice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl GameChoice {
+    fn to_num(&self) -> u32 {
+        match self {
+            GameChoice::Rock => 0,
+            GameChoice::Paper => 1,
+            GameChoice::Scissors => 2,
+        }
+    }
+    fn from_num(n: u32) -> Result<GameChoice, Error> {
+        match n {
+            0 => Ok(GameChoice::Rock),
+            1 => Ok(GameChoice::Paper),
+            2 => Ok(GameChoice::Scissors),
+            _ => Err(Error::invalid_state(
+                "Invalid num entry, expected a number from 0 to 2",
+            )),
+        }
+    }
+
+    fn build_other_player_choice(value: &str) -> Result<Self, Error> {
+        match value {
+            "A" => Ok(GameChoice::Rock),
+            "B" => Ok(GameChoice::Paper),
+            "C" => Ok(GameChoice::Scissors),
+            other => Err(Error::parse(other, "Invalid choice, expected A, B or C")),
+        }
+    }
+
+    fn build_using_first_strategy(code: &Code) -> Self {
+        match code {
+            Code::X => GameChoice::Rock,
+            Code::Y => GameChoice::Paper,
+            Code::Z => GameChoice::Scissors,
+        }
+    }
+
+    fn build_using_second_strategy(
+        code: &Code,
+        other_player_choice: &GameChoice,
+    ) -> Result<Self, Error> {
+        let result_needed = ResultNeed::from(code);
+        let other_player_choice_num = other_player_choice.to_num();
+        match result_needed {
+            ResultNeed::Loose => Ok(GameChoice::from_num((other_player_choice_num + 2) % 3)?),
+            ResultNeed::Draw => Ok(GameChoice::from_num(other_player_choice_num)?),
+            ResultNeed::Win => Ok(GameChoice::from_num((3 + other_player_choice_num + 1) % 3)?),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Round {
+    what_the_other_played: GameChoice,
+    what_i_played: GameChoice,
+}
+
+impl Round {
+    fn new(what_i_played: GameChoice, what_the_other_played: GameChoice) -> Round {
+        Round {
+            what_i_played,
+            what_the_other_played,
+        }
+    }
+
+    fn base_score(&self) -> u32 {
+        match self.what_i_played {
+            GameChoice::Rock => 1,
+            GameChoice::Paper => 2,
+            GameChoice::Scissors => 3,
+        }
+    }
+    fn compete_score(&self) -> u32 {
+        let diff = (3 + self.what_the_other_played.to_num() - self.what_i_played.to_num()) % 3;
+
+        match diff {
+            0 => 3,
+            1 => 0,
+            2 => 6,
+            _ => panic!("Unexpected diff value :( {}", diff),
+        }
+    }
+
+    fn score(&self) -> u32 {
+        return self.base_score() + self.compete_score();
+    }
+}
+
+/// Second column of the strategy guide, either my choice or the result needed
+#[derive(Debug)]
+enum Code {
+    X,
+    Y,
+    Z,
+}
+
+impl TryFrom<&str> for Code {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "X" => Ok(Code::X),
+            "Y" => Ok(Code::Y),
+            "Z" => Ok(Code::Z),
+            other => Err(Error::parse(other, "Invalid choice, expected X, Y or Z")),
+        }
+    }
+}
+
+enum ResultNeed {
+    Loose,
+    Draw,
+    Win,
+}
+
+impl From<&Code> for ResultNeed {
+    fn from(code: &Code) -> Self {
+        match code {
+            Code::X => ResultNeed::Loose,
+            Code::Y => ResultNeed::Draw,
+            Code::Z => ResultNeed::Win,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1_strategy_gives_expected_score() {
+        assert_eq!(
+            compute_score_with_initial_strategy("inputs/2022/input-02-example.txt").unwrap(),
+            15
+        );
+    }
+
+    #[test]
+    fn part_1_strategy_gives_expected_score() {
+        if input_dir::skip_without_key("inputs/2022/input-02.txt") {
+            return;
+        }
+        assert_eq!(
+            compute_score_with_initial_strategy("inputs/2022/input-02.txt").unwrap(),
+            13565
+        );
+    }
+
+    #[test]
+    fn example_part_2_strategy_gives_expected_score() {
+        assert_eq!(
+            compute_score_with_second_strategy("inputs/2022/input-02-example.txt").unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn part_2_strategy_gives_expected_score() {
+        if input_dir::skip_without_key("inputs/2022/input-02.txt") {
+            return;
+        }
+        assert_eq!(
+            compute_score_with_second_strategy("inputs/2022/input-02.txt").unwrap(),
+            12424
+        );
+    }
+}
@@ -1,32 +1,131 @@
-use std::{collections::HashSet, fs};
+use std::collections::HashSet;
 
-pub fn count_distinct_tail_positions(
-    filename: &str,
-    knots_number: usize,
-) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(filename)?;
+use crate::{
+    error::{parse_value, ErrorCollector},
+    input_dir, trace, Answer, CancelToken, Error, Input, InputSet, Param, Part, Puzzle, Solver,
+};
+
+pub struct RopeBridge;
+
+impl Solver for RopeBridge {
+    fn name(&self) -> &'static str {
+        "rope-bridge"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn module(&self) -> &'static str {
+        "rope_bridge"
+    }
+
+    fn title(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_motions(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one move per line, a direction `R`, `L`, `U` or `D` and a number of steps, e.g. `R 4`"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "knots",
+            description: "number of knots of the rope, 2 for part 1 and 10 for part 2",
+            parts: &[Part::Part1, Part::Part2],
+            range: 2..=1_000,
+        }]
+    }
+
+    fn input_sets(&self) -> &'static [InputSet] {
+        &[
+            InputSet::EXAMPLE,
+            InputSet {
+                name: "larger-example",
+                description: "larger example of part 2, where the tail moves",
+            },
+            InputSet::REAL,
+        ]
+    }
+}
 
-    let instructions = content
-        .lines()
-        .map(|line| Instruction::try_from_raw(line))
-        .collect::<Result<Vec<Instruction>, Box<dyn std::error::Error>>>()?;
+pub fn count_distinct_tail_positions(filename: &str, knots_number: usize) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    count_distinct_tail_positions_from_input(&content, knots_number)
+}
 
-    let mut tail_positions = HashSet::new();
+/// Motions of the head of the rope
+pub struct Motions {
+    instructions: Vec<Instruction>,
+}
+
+impl Puzzle for Motions {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .count_distinct_tail_positions(input.params.get("knots", 2)?, &input.cancel)?
+            .into())
+    }
 
-    let mut rope = Rope::new(knots_number)?;
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .count_distinct_tail_positions(input.params.get("knots", 10)?, &input.cancel)?
+            .into())
+    }
+}
 
-    tail_positions.insert((rope.tail().x, rope.tail().y));
+pub fn count_distinct_tail_positions_from_input(
+    content: &str,
+    knots_number: usize,
+) -> Result<usize, Error> {
+    parse_motions(content)?.count_distinct_tail_positions(knots_number, &CancelToken::default())
+}
 
-    for instruction in &instructions {
-        println!("Applying instruction: {}", instruction);
-        for _ in 0..instruction.value {
-            rope.apply_direction(&instruction.direction)?;
-            tail_positions.insert((rope.tail().x, rope.tail().y));
+pub fn parse_motions(content: &str) -> Result<Motions, Error> {
+    let mut instructions = vec![];
+    let mut errors = ErrorCollector::new();
+    for (index, line) in content.lines().enumerate() {
+        let instruction = Instruction::try_from_raw(line).map_err(|e| e.at_line(index, line));
+        if let Some(instruction) = errors.check(instruction)? {
+            instructions.push(instruction);
         }
-        // println!("Rope: {}", rope);
     }
+    errors.finish()?;
+
+    Ok(Motions { instructions })
+}
+
+impl Motions {
+    fn count_distinct_tail_positions(
+        &self,
+        knots_number: usize,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
+        let mut tail_positions = HashSet::new();
+
+        let mut rope = Rope::new(knots_number)?;
+
+        tail_positions.insert((rope.tail().x, rope.tail().y));
+
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            cancel.check(|| format!("{} of {} motions applied", index, self.instructions.len()))?;
+            trace!("Applying instruction: {}", instruction);
+            for _ in 0..instruction.value {
+                rope.apply_direction(&instruction.direction)?;
+                tail_positions.insert((rope.tail().x, rope.tail().y));
+            }
+            // println!("Rope: {}", rope);
+        }
 
-    Ok(tail_positions.len())
+        Ok(tail_positions.len())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -48,19 +147,18 @@ struct Rope {
 }
 
 impl Rope {
-    fn new(knots_number: usize) -> Result<Self, Box<dyn std::error::Error>> {
+    fn new(knots_number: usize) -> Result<Self, Error> {
         if knots_number < 2 {
-            return Err("Unable to create a rope with less than two knots".into());
+            return Err(Error::invalid_state(
+                "Unable to create a rope with less than two knots",
+            ));
         }
         Ok(Rope {
             knots: vec![Position { x: 0, y: 0 }; knots_number],
         })
     }
 
-    fn apply_direction(
-        &mut self,
-        direction: &Direction,
-    ) -> Result<&mut Self, Box<dyn std::error::Error>> {
+    fn apply_direction(&mut self, direction: &Direction) -> Result<&mut Self, Error> {
         match direction {
             Direction::Up => self.knots[0].y += 1,
             Direction::Right => self.knots[0].x += 1,
@@ -117,13 +215,12 @@ impl Rope {
                     self.knots[i].y += y_shift;
                 }
                 other => {
-                    return Err(format!(
+                    return Err(Error::invalid_state(format!(
                         "Unexpected distance between knots {} and knots {}, got {}",
                         i - 1,
                         i,
                         other
-                    )
-                    .into())
+                    )))
                 }
             };
         }
@@ -205,17 +302,16 @@ impl std::fmt::Display for Instruction {
 }
 
 impl Instruction {
-    fn try_from_raw(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    fn try_from_raw(content: &str) -> Result<Self, Error> {
         let elements: Vec<&str> = content.split(" ").collect();
         if elements.len() != 2 {
-            return Err(format!(
-                "Unable to parse content, expected two elements separated by whitespace, got {}",
-                content
-            )
-            .into());
+            return Err(Error::parse(
+                content,
+                "Unable to parse content, expected two elements separated by whitespace",
+            ));
         }
 
-        let instruction_value: usize = elements[1].parse()?;
+        let instruction_value: usize = parse_value(elements[1], "a number of steps")?;
 
         let instruction_direction_str = elements[0];
         let direction = match instruction_direction_str {
@@ -224,11 +320,10 @@ impl Instruction {
             "D" => Direction::Down,
             "L" => Direction::Left,
             other => {
-                return Err(format!(
-                    "Unexpected instruction type, expected `R`, `L`, `U`, `D`, got {}",
-                    other
-                )
-                .into())
+                return Err(Error::parse(
+                    other,
+                    "Unexpected instruction type, expected `R`, `L`, `U`, `D`",
+                ))
             }
         };
         Ok(Instruction {
@@ -245,15 +340,18 @@ mod tests {
     #[test]
     fn example_part_1_has_right_answer() {
         assert_eq!(
-            count_distinct_tail_positions("inputs/input-09-example.txt", 2).unwrap(),
+            count_distinct_tail_positions("inputs/2022/input-09-example.txt", 2).unwrap(),
             13
         );
     }
 
     #[test]
     fn part_1_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-09.txt") {
+            return;
+        }
         assert_eq!(
-            count_distinct_tail_positions("inputs/input-09.txt", 2).unwrap(),
+            count_distinct_tail_positions("inputs/2022/input-09.txt", 2).unwrap(),
             5930
         );
     }
@@ -261,15 +359,18 @@ mod tests {
     #[test]
     fn example_part_2_has_right_answer() {
         assert_eq!(
-            count_distinct_tail_positions("inputs/input-09-example-part-2.txt", 10).unwrap(),
+            count_distinct_tail_positions("inputs/2022/input-09-larger-example.txt", 10).unwrap(),
             36
         );
     }
 
     #[test]
     fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-09.txt") {
+            return;
+        }
         assert_eq!(
-            count_distinct_tail_positions("inputs/input-09.txt", 10).unwrap(),
+            count_distinct_tail_positions("inputs/2022/input-09.txt", 10).unwrap(),
             2443
         );
     }
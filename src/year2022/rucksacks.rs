@@ -0,0 +1,240 @@
+use crate::{error::ErrorCollector, input_dir, Answer, CancelToken, Error, Input, Puzzle, Solver};
+
+pub struct Rucksack;
+
+impl Solver for Rucksack {
+    fn name(&self) -> &'static str {
+        "rucksack"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn module(&self) -> &'static str {
+        "rucksacks"
+    }
+
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        Ok(Box::new(parse_rucksacks(&input.contents)?))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "one rucksack per line, an even number of letters"
+    }
+}
+
+/// Items of each rucksack, one letter per item
+pub struct Rucksacks {
+    rucksacks: Vec<String>,
+}
+
+impl Puzzle for Rucksacks {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(first_part::priorities_sum(&self.rucksacks, &input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(second_part::priorities_sum(&self.rucksacks, &input.cancel)?.into())
+    }
+}
+
+pub fn parse_rucksacks(contents: &str) -> Result<Rucksacks, Error> {
+    let mut rucksacks = vec![];
+    let mut errors = ErrorCollector::new();
+    for (index, line) in contents.lines().enumerate() {
+        let items = line
+            .chars()
+            .try_for_each(|c| item_to_priority(c).map(|_| ()))
+            .map_err(|e| e.at_line(index, line));
+        if errors.check(items)?.is_some() {
+            rucksacks.push(line.to_string());
+        }
+    }
+    errors.finish()?;
+    Ok(Rucksacks { rucksacks })
+}
+
+pub mod first_part {
+    use super::*;
+
+    pub fn compute_priorities_sum(filename: &str) -> Result<u32, Error> {
+        let contents = input_dir::read(filename)?;
+        compute_priorities_sum_from_input(&contents)
+    }
+
+    pub fn compute_priorities_sum_from_input(contents: &str) -> Result<u32, Error> {
+        priorities_sum(
+            &parse_rucksacks(contents)?.rucksacks,
+            &CancelToken::default(),
+        )
+    }
+
+    pub(super) fn priorities_sum(rucksacks: &[String], cancel: &CancelToken) -> Result<u32, Error> {
+        let mut total = 0;
+        let mut errors = ErrorCollector::new();
+        for (index, line) in rucksacks.iter().enumerate() {
+            cancel.check(|| format!("{} of {} rucksacks checked", index, rucksacks.len()))?;
+            let priority = parse_line_into_compartments(line)
+                .and_then(|(left_compartment, right_compartment)| {
+                    find_common_item(left_compartment, right_compartment)
+                        .ok_or(Error::parse(line, "Unable to find the common item"))
+                })
+                .and_then(item_to_priority)
+                .map_err(|e| e.at_line(index, line));
+            if let Some(priority) = errors.check(priority)? {
+                total += priority;
+            }
+        }
+        errors.finish()?;
+        return Ok(total);
+    }
+
+    fn find_common_item(str_a: &str, str_b: &str) -> Option<char> {
+        let common_char_opt = str_a.chars().find(|&c| str_b.contains(c));
+
+        return common_char_opt;
+    }
+
+    fn parse_line_into_compartments(line: &str) -> Result<(&str, &str), Error> {
+        let split_index = line.len() / 2;
+        let (a, b) = line.split_at(split_index);
+        if a.len() != b.len() {
+            return Err(Error::parse(
+                line,
+                format!(
+                    "Invalid split, found two parts with different lengths, {} and {}",
+                    a, b
+                ),
+            ));
+        }
+        return Ok((a, b));
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn example_part_1_has_right_answer() {
+            assert_eq!(
+                compute_priorities_sum("inputs/2022/input-03-example.txt").unwrap(),
+                157
+            );
+        }
+
+        #[test]
+        fn part_1_has_right_answer() {
+            if input_dir::skip_without_key("inputs/2022/input-03.txt") {
+                return;
+            }
+            assert_eq!(
+                compute_priorities_sum("inputs/2022/input-03.txt").unwrap(),
+                7848
+            );
+        }
+    }
+}
+
+pub mod second_part {
+    use super::*;
+
+    pub fn compute_priorities_sum(filename: &str) -> Result<u32, Error> {
+        let contents = input_dir::read(filename)?;
+        compute_priorities_sum_from_input(&contents)
+    }
+
+    pub fn compute_priorities_sum_from_input(contents: &str) -> Result<u32, Error> {
+        priorities_sum(
+            &parse_rucksacks(contents)?.rucksacks,
+            &CancelToken::default(),
+        )
+    }
+
+    pub(super) fn priorities_sum(lines: &[String], cancel: &CancelToken) -> Result<u32, Error> {
+        let mut total = 0;
+        let mut i = 0;
+        let number_of_groups = lines.len() / 3;
+        let mut errors = ErrorCollector::new();
+        while i < number_of_groups {
+            cancel.check(|| format!("{} of {} groups checked", i, number_of_groups))?;
+            let priority = lines[3 * i]
+                .chars()
+                .find(|&c| {
+                    return lines[3 * i + 1].contains(c) && lines[3 * i + 2].contains(c);
+                })
+                .ok_or(Error::parse(
+                    &lines[3 * i],
+                    "Unable to find common item in the group",
+                ))
+                .and_then(item_to_priority)
+                .map_err(|e| e.at_line(3 * i, &lines[3 * i]));
+            if let Some(priority) = errors.check(priority)? {
+                total += priority;
+            }
+            i += 1;
+        }
+        errors.finish()?;
+        return Ok(total);
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn example_part_2_has_right_answer() {
+            assert_eq!(
+                compute_priorities_sum("inputs/2022/input-03-example.txt").unwrap(),
+                70
+            );
+        }
+
+        #[test]
+        fn part_2_has_right_answer() {
+            if input_dir::skip_without_key("inputs/2022/input-03.txt") {
+                return;
+            }
+            assert_eq!(
+                compute_priorities_sum("inputs/2022/input-03.txt").unwrap(),
+                2616
+            );
+        }
+    }
+}
+
+fn item_to_priority(c: char) -> Result<u32, Error> {
+    let a: u32 = c.try_into().or(Err(Error::parse(
+        &c.to_string(),
+        "Unable to convert the char to a `u32`",
+    )))?;
+    let number_in_alphabet = 26;
+    if a >= 64 && a <= 64 + number_in_alphabet {
+        return Ok(a - 64 + number_in_alphabet);
+    }
+    if a >= 97 && a <= 97 + number_in_alphabet {
+        return Ok(a - 96);
+    }
+    return Err(Error::parse(
+        &c.to_string(),
+        format!(
+            "Conversion of char is in an unmanaged range, found code {}",
+            a
+        ),
+    )
+    .with_hint(&format!(
+        "a code between {} and {}, or between {} and {}",
+        64,
+        64 + number_in_alphabet,
+        97,
+        97 + number_in_alphabet
+    )));
+}
@@ -1,27 +1,73 @@
 use std::{
     collections::{HashMap, HashSet},
-    fmt, fs,
+    fmt,
 };
 
+use crate::{
+    error::ErrorCollector, input_dir, trace, Answer, CancelToken, Error, Input, Puzzle, Solver,
+};
+
+pub struct TreeHouse;
+
+impl Solver for TreeHouse {
+    fn name(&self) -> &'static str {
+        "tree-house"
+    }
+
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn module(&self) -> &'static str {
+        "tree_house"
+    }
+
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn parse(&self, input: &Input) -> Result<Box<dyn Puzzle>, Error> {
+        let forest = Forest::try_from_raw_grid(&input.contents)?;
+
+        trace!("Forest: {}", forest);
+
+        Ok(Box::new(forest))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "a grid of tree heights, one digit per tree"
+    }
+}
+
 #[allow(dead_code)]
-pub fn count_visible_trees(filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(filename)?;
+pub fn count_visible_trees(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    count_visible_trees_from_input(&content)
+}
 
+pub fn count_visible_trees_from_input(content: &str) -> Result<usize, Error> {
     let forest = Forest::try_from_raw_grid(content)?;
 
-    println!("Forest: {}", forest);
+    trace!("Forest: {}", forest);
 
-    Ok(forest.inner_visible_trees().len() + 4 * (forest.dimension() - 1))
+    forest.count_visible_trees(&CancelToken::default())
 }
 
-pub fn find_highest_scenic_score(filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(filename)?;
+pub fn find_highest_scenic_score(filename: &str) -> Result<usize, Error> {
+    let content = input_dir::read(filename)?;
+    find_highest_scenic_score_from_input(&content)
+}
 
+pub fn find_highest_scenic_score_from_input(content: &str) -> Result<usize, Error> {
     let forest = Forest::try_from_raw_grid(content)?;
 
-    println!("Forest: {}", forest);
+    trace!("Forest: {}", forest);
 
-    Ok(forest.inner_visible_trees_highest_scenic_score())
+    forest.inner_visible_trees_highest_scenic_score(&CancelToken::default())
 }
 
 #[derive(Debug)]
@@ -37,6 +83,18 @@ struct Forest {
     grid: Vec<Vec<u8>>,
 }
 
+impl Puzzle for Forest {
+    fn part_1(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self.count_visible_trees(&input.cancel)?.into())
+    }
+
+    fn part_2(&self, input: &Input) -> Result<Answer, Error> {
+        Ok(self
+            .inner_visible_trees_highest_scenic_score(&input.cancel)?
+            .into())
+    }
+}
+
 impl fmt::Display for Forest {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let displayed = &self
@@ -55,7 +113,14 @@ impl fmt::Display for Forest {
 }
 
 impl Forest {
-    fn inner_visible_trees_highest_scenic_score(&self) -> usize {
+    fn count_visible_trees(&self, cancel: &CancelToken) -> Result<usize, Error> {
+        Ok(self.inner_visible_trees(cancel)?.len() + 4 * (self.dimension() - 1))
+    }
+
+    fn inner_visible_trees_highest_scenic_score(
+        &self,
+        cancel: &CancelToken,
+    ) -> Result<usize, Error> {
         let dimension = self.dimension();
 
         let mut highest_trees_from_top = self.grid[0].clone();
@@ -64,6 +129,7 @@ impl Forest {
         let mut visible_tree_scenic_scores: HashMap<(usize, usize), ScenicScore> = HashMap::new();
 
         for i in 1..dimension - 1 {
+            cancel.check(|| format!("{} of {} rows scanned", i - 1, dimension - 2))?;
             let mut highest_tree_from_left = self.grid[i][0];
 
             let mut highest_tree_from_right = self.grid[dimension - i - 1][dimension - 1];
@@ -151,7 +217,11 @@ impl Forest {
         }
 
         let mut max_score = 0;
-        for ((row, column), scenic_score) in visible_tree_scenic_scores.iter_mut() {
+        let visible_tree_count = visible_tree_scenic_scores.len();
+        for (index, ((row, column), scenic_score)) in
+            visible_tree_scenic_scores.iter_mut().enumerate()
+        {
+            cancel.check(|| format!("{} of {} visible trees scored", index, visible_tree_count))?;
             let visible_tree = self.grid[*row][*column];
             if scenic_score.top == 0 {
                 let mut i = row - 1;
@@ -210,10 +280,10 @@ impl Forest {
             }
         }
 
-        max_score
+        Ok(max_score)
     }
 
-    fn inner_visible_trees(&self) -> HashSet<(usize, usize)> {
+    fn inner_visible_trees(&self, cancel: &CancelToken) -> Result<HashSet<(usize, usize)>, Error> {
         let dimension = self.dimension();
 
         let mut highest_trees_from_top = self.grid[0].clone();
@@ -222,6 +292,7 @@ impl Forest {
         let mut visible_trees = HashSet::new();
 
         for i in 1..dimension - 1 {
+            cancel.check(|| format!("{} of {} rows scanned", i - 1, dimension - 2))?;
             let mut highest_tree_from_left = self.grid[i][0];
 
             let mut highest_tree_from_right = self.grid[dimension - i - 1][dimension - 1];
@@ -262,58 +333,84 @@ impl Forest {
             }
         }
 
-        visible_trees
+        Ok(visible_trees)
     }
 
     fn dimension(&self) -> usize {
         self.grid.len()
     }
 
-    fn try_from_raw_grid(content: String) -> Result<Self, Box<dyn std::error::Error>> {
+    fn try_from_raw_grid(content: &str) -> Result<Self, Error> {
         let mut grid: Vec<Vec<u8>> = vec![];
 
-        let first_line = content.lines().take(1).collect::<Vec<&str>>()[0];
-        let first_row = line_to_row(first_line)?;
+        let Some(first_line) = content.lines().next() else {
+            return Err(Error::parse(
+                "",
+                "Empty grid, expected at least one row of tree heights",
+            ));
+        };
+        let first_row = line_to_row(first_line).map_err(|e| e.at_line(0, first_line))?;
 
         let row_dimension = first_row.len();
 
         grid.push(first_row);
+        let mut errors = ErrorCollector::new();
 
-        for line in content.lines().skip(1) {
-            let row = line_to_row(line)?;
+        for (index, line) in content.lines().enumerate().skip(1) {
+            let Some(row) = errors.check(line_to_row(line).map_err(|e| e.at_line(index, line)))?
+            else {
+                continue;
+            };
             if row.len() != row_dimension {
-                return Err(format!(
-                    "Found line of different length than the first one, expected {}, got {}",
-                    row_dimension,
-                    row.len()
-                )
-                .into());
+                errors.report(
+                    Error::parse(
+                        line,
+                        format!(
+                            "Found a line of {} trees instead of {} as on the first line",
+                            row.len(),
+                            row_dimension
+                        ),
+                    )
+                    .at_line(index, line)
+                    .with_hint(&format!(
+                        "lines of {} trees, as long as the first one",
+                        row_dimension
+                    )),
+                )?;
+                continue;
             }
             grid.push(row);
         }
+        errors.finish()?;
 
         if grid.len() != row_dimension {
-            return Err(format!("Dimensions of the grid are invalid, expected a square, got a rectangle with {} rows and {} columns", row_dimension, grid.len()).into());
+            return Err(Error::parse("", format!("Dimensions of the grid are invalid, expected a square, got a rectangle with {} rows and {} columns", grid.len(), row_dimension)));
         }
 
         Ok(Forest { grid })
     }
 }
 
-fn line_to_row(line: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+fn line_to_row(line: &str) -> Result<Vec<u8>, Error> {
     line.chars()
-        .map(|c| {
+        .enumerate()
+        .map(|(column, c)| {
             c.to_digit(10)
-                .ok_or(format!("Unable to parse char {} into an integer", c))
+                .ok_or(
+                    Error::parse(&c.to_string(), "Unable to parse char into an integer")
+                        .at_column(column),
+                )
                 .and_then(|x| {
                     let y: Result<u8, _> = x.try_into().map_err(|_| {
-                        format!("Unable to convert parsed value {} from u32 to u8", x)
+                        Error::invalid_state(format!(
+                            "Unable to convert parsed value {} from u32 to u8",
+                            x
+                        ))
                     });
                     y
                 })
         })
         .collect::<Result<Vec<u8>, _>>()
-        .map_err(|e| e.into())
 }
 
 #[cfg(test)]
@@ -323,29 +420,48 @@ mod tests {
     #[test]
     fn example_part_1_has_right_answer() {
         assert_eq!(
-            count_visible_trees("inputs/input-08-example.txt").unwrap(),
+            count_visible_trees("inputs/2022/input-08-example.txt").unwrap(),
             21
         );
     }
 
     #[test]
     fn part_1_has_right_answer() {
-        assert_eq!(count_visible_trees("inputs/input-08.txt").unwrap(), 1816);
+        if input_dir::skip_without_key("inputs/2022/input-08.txt") {
+            return;
+        }
+        assert_eq!(
+            count_visible_trees("inputs/2022/input-08.txt").unwrap(),
+            1816
+        );
     }
 
     #[test]
     fn example_part_2_has_right_answer() {
         assert_eq!(
-            find_highest_scenic_score("inputs/input-08-example.txt").unwrap(),
+            find_highest_scenic_score("inputs/2022/input-08-example.txt").unwrap(),
             8
         );
     }
 
     #[test]
     fn part_2_has_right_answer() {
+        if input_dir::skip_without_key("inputs/2022/input-08.txt") {
+            return;
+        }
         assert_eq!(
-            find_highest_scenic_score("inputs/input-08.txt").unwrap(),
+            find_highest_scenic_score("inputs/2022/input-08.txt").unwrap(),
             383520
         );
     }
+
+    #[test]
+    fn invalid_grids_are_reported() {
+        let error = count_visible_trees_from_input("").err().unwrap();
+        assert!(matches!(error, Error::Parse(_)));
+        let error = count_visible_trees_from_input("123\n456\n").err().unwrap();
+        assert!(error
+            .to_string()
+            .contains("got a rectangle with 2 rows and 3 columns"));
+    }
 }